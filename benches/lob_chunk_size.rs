@@ -0,0 +1,75 @@
+//! Compares streaming a large BLOB with different LOB chunk sizes
+//!
+//! Requires a database, the connection url is read from `OCI_DATABASE_URL`
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use diesel::connection::LoadConnection;
+use diesel::prelude::*;
+use diesel_oci::OciConnection;
+
+table! {
+    bench_lob_chunk_size (id) {
+        id -> Integer,
+        data -> diesel_oci::Blob,
+    }
+}
+
+const BLOB_SIZE: usize = 32 * 1024 * 1024;
+
+fn connection() -> OciConnection {
+    dotenv::dotenv().ok();
+    let url = std::env::var("OCI_DATABASE_URL").expect("OCI_DATABASE_URL must be set");
+    let mut conn = OciConnection::establish(&url).unwrap();
+    let _ = diesel::sql_query("DROP TABLE bench_lob_chunk_size").execute(&mut conn);
+    diesel::sql_query(
+        "CREATE TABLE bench_lob_chunk_size (id NUMBER(10) NOT NULL, data BLOB NOT NULL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    diesel::sql_query("INSERT INTO bench_lob_chunk_size VALUES (1, :1)")
+        .bind::<diesel_oci::Blob, _>(vec![0x5A_u8; BLOB_SIZE])
+        .execute(&mut conn)
+        .unwrap();
+    conn.set_lob_locators(true);
+    conn
+}
+
+fn lob_chunk_size(c: &mut Criterion) {
+    use self::bench_lob_chunk_size::dsl::*;
+
+    let mut conn = connection();
+
+    let mut group = c.benchmark_group("lob_chunk_size");
+    group.throughput(Throughput::Bytes(BLOB_SIZE as u64));
+    // `None` uses the chunk size reported by oracle
+    for chunk_size in [
+        None,
+        Some(64 * 1024),
+        Some(1024 * 1024),
+        Some(8 * 1024 * 1024),
+    ] {
+        let name = chunk_size.map_or_else(|| "default".to_owned(), |s| s.to_string());
+        if let Some(chunk_size) = chunk_size.and_then(std::num::NonZeroUsize::new) {
+            conn.set_lob_chunk_size(chunk_size);
+        }
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                for row in conn.load(bench_lob_chunk_size.select(data)).unwrap() {
+                    let mut reader = row.unwrap().blob(0).unwrap().unwrap();
+                    let read = std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+                    assert_eq!(read, BLOB_SIZE as u64);
+                }
+            })
+        });
+    }
+    group.finish();
+
+    let _ = diesel::sql_query("DROP TABLE bench_lob_chunk_size").execute(&mut conn);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = lob_chunk_size
+}
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+//! Compares inserts binding borrowed string slices with inserts
+//! binding freshly allocated strings
+//!
+//! Requires a database, the connection url is read from `OCI_DATABASE_URL`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use diesel::prelude::*;
+use diesel_oci::OciConnection;
+
+table! {
+    bench_string_binds (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+const ROWS: usize = 1000;
+
+fn connection() -> OciConnection {
+    dotenv::dotenv().ok();
+    let url = std::env::var("OCI_DATABASE_URL").expect("OCI_DATABASE_URL must be set");
+    let mut conn = OciConnection::establish(&url).unwrap();
+    let _ = diesel::sql_query("DROP TABLE bench_string_binds").execute(&mut conn);
+    diesel::sql_query(
+        "CREATE TABLE bench_string_binds (id NUMBER(10) NOT NULL, name VARCHAR2(100) NOT NULL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    conn
+}
+
+fn string_binds(c: &mut Criterion) {
+    use self::bench_string_binds::dsl::*;
+
+    let mut conn = connection();
+    let names = (0..ROWS)
+        .map(|i| format!("some name of a medium length {}", i))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("string_binds");
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            conn.test_transaction::<_, diesel::result::Error, _>(|conn| {
+                for (i, n) in names.iter().enumerate() {
+                    diesel::insert_into(bench_string_binds)
+                        .values((id.eq(i as i32), name.eq(n.as_str())))
+                        .execute(conn)?;
+                }
+                Ok(())
+            })
+        })
+    });
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            conn.test_transaction::<_, diesel::result::Error, _>(|conn| {
+                for (i, n) in names.iter().enumerate() {
+                    diesel::insert_into(bench_string_binds)
+                        .values((id.eq(i as i32), name.eq(n.to_owned())))
+                        .execute(conn)?;
+                }
+                Ok(())
+            })
+        })
+    });
+    group.finish();
+
+    let _ = diesel::sql_query("DROP TABLE bench_string_binds").execute(&mut conn);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = string_binds
+}
+criterion_main!(benches);
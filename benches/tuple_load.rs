@@ -0,0 +1,73 @@
+//! Compares loading a large result via `load` with loading it via
+//! `OciConnection::load_tuples`
+//!
+//! Requires a database, the connection url is read from `OCI_DATABASE_URL`
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use diesel::prelude::*;
+use diesel_oci::OciConnection;
+
+table! {
+    bench_tuple_load (id) {
+        id -> Integer,
+        name -> Text,
+        score -> Double,
+    }
+}
+
+const ROWS: usize = 1_000_000;
+
+fn connection() -> OciConnection {
+    dotenv::dotenv().ok();
+    let url = std::env::var("OCI_DATABASE_URL").expect("OCI_DATABASE_URL must be set");
+    let mut conn = OciConnection::establish(&url).unwrap();
+    let _ = diesel::sql_query("DROP TABLE bench_tuple_load").execute(&mut conn);
+    diesel::sql_query(
+        "CREATE TABLE bench_tuple_load (id NUMBER(10) NOT NULL, \
+         name VARCHAR2(100) NOT NULL, score BINARY_DOUBLE NOT NULL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    diesel::sql_query(format!(
+        "INSERT INTO bench_tuple_load \
+         SELECT LEVEL, 'some name of a medium length ' || LEVEL, LEVEL / 7 \
+         FROM DUAL CONNECT BY LEVEL <= {}",
+        ROWS
+    ))
+    .execute(&mut conn)
+    .unwrap();
+    conn.set_prefetch_rows(1000);
+    conn
+}
+
+fn tuple_load(c: &mut Criterion) {
+    use self::bench_tuple_load::dsl::*;
+
+    let mut conn = connection();
+    let query = || bench_tuple_load.select((id, name, score));
+
+    let mut group = c.benchmark_group("tuple_load");
+    group.throughput(Throughput::Elements(ROWS as u64));
+    group.bench_function("load", |b| {
+        b.iter(|| {
+            let rows = query().load::<(i32, String, f64)>(&mut conn).unwrap();
+            assert_eq!(rows.len(), ROWS);
+        })
+    });
+    group.bench_function("load_tuples", |b| {
+        b.iter(|| {
+            let rows = conn.load_tuples::<(i32, String, f64), _>(query()).unwrap();
+            assert_eq!(rows.len(), ROWS);
+        })
+    });
+    group.finish();
+
+    let _ = diesel::sql_query("DROP TABLE bench_tuple_load").execute(&mut conn);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = tuple_load
+}
+criterion_main!(benches);
@@ -29,9 +29,13 @@
 //!
 //! # Feature flags
 //!
+//! * `bigdecimal` Enables support for the `bigdecimal` crate
 //! * `chrono` Enables support for the `chrono` crate
 //! * `r2d2` Enables support for r2d2 connection pooling
 //! * `dynamic-schema` Enables support for diesel-dynamic-schema
+//! * `rust_decimal` Enables support for the `rust_decimal` crate
+//! * `session-stats` Enables reading the statistics of the current session
+//!   via `OciConnection::session_statistics`, which requires access to `V$MYSTAT`
 
 pub mod oracle;
 
@@ -33,7 +33,7 @@ impl DieselReserveSpecialization for Oracle {}
 impl SqlDialect for Oracle {
     type ReturningClause = OracleReturningClause;
 
-    type OnConflictClause = sql_dialect::on_conflict_clause::DoesNotSupportOnConflictClause;
+    type OnConflictClause = OracleOnConflictClause;
 
     type InsertWithDefaultKeyword = sql_dialect::default_keyword_for_insert::IsoSqlDefaultKeyword;
     type BatchInsertSupport = OracleStyleBatchInsert;
@@ -43,11 +43,26 @@ impl SqlDialect for Oracle {
     type ExistsSyntax = OracleExistsSyntax;
 
     type ConcatClause = sql_dialect::concat_clause::ConcatWithPipesClause;
-    type ArrayComparison = sql_dialect::array_comparison::AnsiSqlArrayComparison;
+    type ArrayComparison = OracleArrayComparison;
     type SelectStatementSyntax = sql_dialect::select_statement_syntax::AnsiSqlSelectStatement;
 }
 
 pub struct OracleStyleBatchInsert;
 pub struct OracleReturningClause;
+/// Upserts via `.on_conflict()` are executed as `MERGE` statement
+///
+/// Inserts of a single row or of the rows of a query, like
+/// `insert_into(users).values(staging.select((staging::id, staging::name)))`,
+/// are supported with a conflict target consisting of columns, followed
+/// by `.do_nothing()` or `.do_update()`. Other upserts fail with
+/// `Error::QueryBuilderError` before they are sent to the database. The
+/// rows of a query need to be unique for the conflict target (ORA-30926),
+/// the columns of the conflict target cannot be updated (ORA-38104), and
+/// oracle does not support a `RETURNING` clause for `MERGE` statements.
+pub struct OracleOnConflictClause;
 pub struct OracleDualForEmptySelectClause;
 pub struct OracleExistsSyntax;
+pub struct OracleArrayComparison;
+
+impl sql_dialect::on_conflict_clause::SupportsOnConflictClause for OracleOnConflictClause {}
+impl sql_dialect::on_conflict_clause::PgLikeOnConflictClause for OracleOnConflictClause {}
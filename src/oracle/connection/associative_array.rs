@@ -0,0 +1,155 @@
+use super::{ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+use std::collections::{BTreeMap, HashMap};
+
+/// A PL/SQL associative array (`TABLE OF … INDEX BY BINARY_INTEGER`)
+///
+/// Associative arrays may be sparse, so each value is stored together with
+/// its index. Converting a `Vec` into an associative array uses the indices
+/// `1..=len`, as it is common for PL/SQL code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssociativeArray<T> {
+    values: BTreeMap<i32, T>,
+}
+
+impl<T> AssociativeArray<T> {
+    /// Construct an empty associative array
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Insert a value at the given index, returning the previous value at that index
+    pub fn insert(&mut self, index: i32, value: T) -> Option<T> {
+        self.values.insert(index, value)
+    }
+
+    /// Get the value at the given index
+    pub fn get(&self, index: i32) -> Option<&T> {
+        self.values.get(&index)
+    }
+
+    /// The number of values
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the array contains no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate over all indices and values, ordered by index
+    pub fn iter(&self) -> impl Iterator<Item = (i32, &T)> {
+        self.values.iter().map(|(idx, v)| (*idx, v))
+    }
+
+    /// All values, ordered by index
+    pub fn into_values(self) -> Vec<T> {
+        self.values.into_values().collect()
+    }
+}
+
+impl<T> Default for AssociativeArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for AssociativeArray<T> {
+    fn from(values: Vec<T>) -> Self {
+        (1..).zip(values).collect()
+    }
+}
+
+impl<T> From<HashMap<i32, T>> for AssociativeArray<T> {
+    fn from(values: HashMap<i32, T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<BTreeMap<i32, T>> for AssociativeArray<T> {
+    fn from(values: BTreeMap<i32, T>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T> FromIterator<(i32, T)> for AssociativeArray<T> {
+    fn from_iter<I: IntoIterator<Item = (i32, T)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl OciConnection {
+    /// Execute a PL/SQL block that receives and returns an associative array
+    ///
+    /// `input` is bound to the `:input` placeholder as value of the type
+    /// `input_type`, `:output` is bound as out parameter of the type `output_type`
+    /// and returned afterwards. Both types must be declared in a package
+    /// specification and are given as `PACKAGE.TYPE` (or `SCHEMA.PACKAGE.TYPE`):
+    ///
+    /// ```no_run
+    /// # use diesel_oci::{AssociativeArray, OciConnection};
+    /// # fn run_test(conn: &mut OciConnection) -> diesel::QueryResult<()> {
+    /// let doubled: AssociativeArray<i64> = conn.call_with_associative_array(
+    ///     "BEGIN my_pkg.double_values(:input, :output); END;",
+    ///     "MY_PKG.NUMBER_ARRAY",
+    ///     &AssociativeArray::from(vec![1_i64, 2, 3]),
+    ///     "MY_PKG.NUMBER_ARRAY",
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// All values are transferred in a single round trip, which makes this
+    /// a fast way to pass bulk data to stored procedures.
+    pub fn call_with_associative_array<T, U>(
+        &mut self,
+        plsql: &str,
+        input_type: &str,
+        input: &AssociativeArray<T>,
+        output_type: &str,
+    ) -> QueryResult<AssociativeArray<U>>
+    where
+        T: oracle::sql_type::ToSql,
+        U: oracle::sql_type::FromSql,
+    {
+        let input_type = self.object_type(input_type)?;
+        let mut collection = input_type.new_collection().map_err(ErrorHelper::from)?;
+        for (idx, value) in input.iter() {
+            collection.set(idx, value).map_err(ErrorHelper::from)?;
+        }
+        let output_type = self.object_type(output_type)?;
+
+        let mut stmt = self
+            .raw
+            .statement(plsql)
+            .build()
+            .map_err(ErrorHelper::from)?;
+        stmt.execute_named(&[("input", &collection), ("output", &output_type)])
+            .map_err(ErrorHelper::from)?;
+        let output = stmt
+            .bind_value::<_, Option<oracle::sql_type::Collection>>("output")
+            .map_err(ErrorHelper::from)?;
+
+        let mut result = AssociativeArray::new();
+        if let Some(output) = output {
+            if output.size().map_err(ErrorHelper::from)? > 0 {
+                let mut idx = output.first_index().map_err(ErrorHelper::from)?;
+                loop {
+                    let value = output.get(idx).map_err(ErrorHelper::from)?;
+                    result.insert(idx, value);
+                    match output.next_index(idx) {
+                        Ok(next) => idx = next,
+                        Err(oracle::Error::NoDataFound) => break,
+                        Err(e) => return Err(ErrorHelper::from(e).into()),
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
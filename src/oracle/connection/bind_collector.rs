@@ -8,14 +8,163 @@ use std::ops::Deref;
 #[derive(Default)]
 pub struct OracleBindCollector<'a> {
     pub(crate) binds: Vec<(String, BindValue<'a>)>,
+    // the diesel side type of each bind, used for error messages
+    bind_types: Vec<OciDataType>,
+    positional: bool,
+    blank_padded: bool,
+    // set if the values were pushed without placeholder, like the binds
+    // of `diesel::sql_query`, so the query uses its own placeholder names
+    own_placeholders: bool,
+}
+
+impl<'a> OracleBindCollector<'a> {
+    /// Constructs a new bind collector
+    ///
+    /// If `positional` is set the values are bound by their position
+    /// instead of by the `:inN` names, to match the placeholders of an
+    /// [`OciQueryBuilder::positional`](crate::query_builder::OciQueryBuilder::positional).
+    /// If `blank_padded` is set text values are bound as `CHAR`, see
+    /// [`OciConnection::set_blank_padded_string_binds`](crate::OciConnection::set_blank_padded_string_binds)
+    pub(crate) fn new(positional: bool, blank_padded: bool) -> Self {
+        Self {
+            binds: Vec::new(),
+            bind_types: Vec::new(),
+            positional,
+            blank_padded,
+            own_placeholders: false,
+        }
+    }
+
+    /// Record the number of placeholders the query builder emitted
+    ///
+    /// If there are less placeholders than values, the values are bound
+    /// to the placeholder names used by the statement in the order of
+    /// their first occurrence instead of to the generated `:inN` names.
+    pub(crate) fn set_placeholder_count(&mut self, count: usize) {
+        self.own_placeholders = count < self.binds.len();
+    }
+
+    pub(crate) fn is_positional(&self) -> bool {
+        self.positional
+    }
+
+    /// Bind all collected values to the given statement
+    ///
+    /// A value that cannot be converted to the oracle type of the bind
+    /// results in a `ParseError` naming the bind parameter and its type
+    pub(crate) fn bind_to(&self, stmt: &mut oracle::Statement<'_>) -> oracle::Result<()> {
+        let own_names = if self.own_placeholders && !self.positional {
+            let names = stmt
+                .bind_names()
+                .into_iter()
+                .map(str::to_owned)
+                .collect::<Vec<_>>();
+            if names.len() < self.binds.len() {
+                return Err(oracle::Error::InvalidBindIndex(names.len() + 1));
+            }
+            Some(names)
+        } else {
+            None
+        };
+        for (idx, (name, value)) in self.binds.iter().enumerate() {
+            let name = match &own_names {
+                Some(names) => &names[idx],
+                None => name,
+            };
+            let ret = if self.positional {
+                stmt.bind(idx + 1, &**value)
+            } else {
+                stmt.bind(name as &str, &**value)
+            };
+            match ret {
+                Ok(()) => {}
+                Err(oracle::Error::ParseError(e)) => {
+                    return Err(oracle::Error::ParseError(
+                        format!(
+                            "Failed to bind the value of `:{}` as {:?}: {}",
+                            name, self.bind_types[idx], e
+                        )
+                        .into(),
+                    ));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The maximal size in bytes of a `CHAR` value
+const MAX_CHAR_BIND_SIZE: usize = 2000;
+
+// Binds a text value as `CHAR`, so that oracle uses blank-padded
+// comparison semantics when comparing it with a `CHAR` column
+struct CharWrapper(String);
+
+impl oracle::sql_type::ToSql for CharWrapper {
+    fn oratype(&self, _conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        Ok(oracle::sql_type::OracleType::Char(
+            self.0.len().max(1) as u32
+        ))
+    }
+
+    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+        val.set(&self.0)?;
+        Ok(())
+    }
 }
 
 pub enum BindValue<'a> {
     Owned(Box<dyn oracle::sql_type::ToSql>),
     Borrowed(&'a dyn oracle::sql_type::ToSql),
+    /// A borrowed string, bound without copying it
+    ///
+    /// `str` is unsized, so it cannot be stored as `Borrowed`
+    Str(&'a str),
+    /// A borrowed text value, bound as temporary `CLOB` without copying it
+    Clob(ClobRef<'a>),
+    /// A borrowed binary value, bound as temporary `BLOB` without copying it
+    Blob(BlobRef<'a>),
     NotSet(OciDataType),
 }
 
+// Oracle creates a temporary LOB for each value bound as `CLOB`. It is
+// owned by the bind variable of the statement and freed as soon as the
+// statement is dropped. Statements without result rows are dropped directly
+// after executing them, both for successful and failing statements. The
+// rows of a query are streamed by the row iterator, which owns the
+// statement, so the temporary LOB lives as long as the iterator.
+pub struct ClobRef<'a>(pub(crate) &'a str);
+
+impl<'a> oracle::sql_type::ToSql for ClobRef<'a> {
+    fn oratype(&self, _conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        Ok(oracle::sql_type::OracleType::CLOB)
+    }
+
+    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+        val.set(&self.0)?;
+        Ok(())
+    }
+}
+
+// The content of a temporary `BLOB` is written to the database while
+// binding, the bind variable itself only holds the LOB locator. So binding
+// large values as `BLOB` keeps the memory usage bounded, even for batches
+// of many records, which otherwise hold the content of all records in
+// their bind buffers until the batch is executed.
+pub struct BlobRef<'a>(pub(crate) &'a [u8]);
+
+impl<'a> oracle::sql_type::ToSql for BlobRef<'a> {
+    fn oratype(&self, _conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        Ok(oracle::sql_type::OracleType::BLOB)
+    }
+
+    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+        val.set(&self.0)?;
+        Ok(())
+    }
+}
+
 impl<'a> Deref for BindValue<'a> {
     type Target = dyn oracle::sql_type::ToSql + 'a;
 
@@ -23,23 +172,41 @@ impl<'a> Deref for BindValue<'a> {
         match self {
             BindValue::Owned(b) => &**b,
             BindValue::Borrowed(b) => *b,
+            BindValue::Str(s) => s,
+            BindValue::Clob(c) => c,
+            BindValue::Blob(b) => b,
             BindValue::NotSet(d) => default_value(d),
         }
     }
 }
 
+// `None` values are bound as `NULL` of the corresponding type and not as some
+// sentinel value, so that oracle still applies `DEFAULT ON NULL` column defaults.
+// The type matches the one of non-null values where possible, as the bind
+// types of a batch are fixed by its first record, which might be all `NULL`.
 fn default_value(d: &'_ OciDataType) -> &'static dyn oracle::sql_type::ToSql {
     match d {
-        OciDataType::Bool | OciDataType::SmallInt | OciDataType::Integer | OciDataType::BigInt => {
-            &oracle::sql_type::OracleType::Number(0, 0)
-        }
+        OciDataType::Bool
+        | OciDataType::SmallInt
+        | OciDataType::Integer
+        | OciDataType::BigInt
+        | OciDataType::Numeric => &oracle::sql_type::OracleType::Number(0, 0),
         OciDataType::Float => &oracle::sql_type::OracleType::BinaryFloat,
         OciDataType::Double => &oracle::sql_type::OracleType::BinaryDouble,
-        OciDataType::Text => &oracle::sql_type::OracleType::Varchar2(0),
-        OciDataType::Binary => &oracle::sql_type::OracleType::BLOB,
+        OciDataType::Text | OciDataType::Rowid => &oracle::sql_type::OracleType::Varchar2(0),
+        // values up to `MAX_RAW_BIND_SIZE` are bound as `RAW`, a `NULL` `BLOB`
+        // fails with `ORA-00932` when compared with a `RAW` column
+        OciDataType::Binary => &oracle::sql_type::OracleType::Raw(0),
         OciDataType::Date => &oracle::sql_type::OracleType::Date,
         OciDataType::Time => unimplemented!("No time support in the oracle crate yet"),
-        OciDataType::Timestamp => &oracle::sql_type::OracleType::Timestamp(0),
+        OciDataType::Timestamp => &oracle::sql_type::OracleType::Timestamp(9),
+        OciDataType::IntervalYM => &oracle::sql_type::OracleType::IntervalYM(9),
+        OciDataType::IntervalDS => &oracle::sql_type::OracleType::IntervalDS(9, 9),
+        OciDataType::TimestampTz => &oracle::sql_type::OracleType::TimestampTZ(9),
+        OciDataType::Clob => &oracle::sql_type::OracleType::CLOB,
+        OciDataType::Blob => &oracle::sql_type::OracleType::BLOB,
+        // the object type is unknown here
+        OciDataType::Object => &oracle::sql_type::OracleType::Varchar2(0),
     }
 }
 
@@ -64,9 +231,21 @@ impl<'a> BindCollector<'a, Oracle> for OracleBindCollector<'a> {
             bind.to_sql(&mut out).unwrap();
             out.into_inner()
         };
+        let out = match out {
+            BindValue::Str(s) if self.blank_padded && s.len() <= MAX_CHAR_BIND_SIZE => {
+                BindValue::Owned(Box::new(CharWrapper(s.to_owned())))
+            }
+            out => out,
+        };
         let len = self.binds.len();
+        let name = if self.positional {
+            (len + 1).to_string()
+        } else {
+            format!("in{}", len)
+        };
 
-        self.binds.push((format!("in{}", len), out));
+        self.binds.push((name, out));
+        self.bind_types.push(ty);
 
         Ok(())
     }
@@ -0,0 +1,92 @@
+use std::io::Write;
+
+use super::OciConnection;
+use crate::oracle::Oracle;
+use diesel::connection::LoadConnection;
+use diesel::deserialize::FromSql;
+use diesel::expression::QueryMetadata;
+use diesel::query_builder::{AsQuery, QueryFragment, QueryId};
+use diesel::result::{Error, QueryResult};
+use diesel::row::{Field, Row};
+use diesel::sql_types::Text;
+
+impl OciConnection {
+    /// Write the result of `query` as CSV to `out`
+    ///
+    /// The first line contains the column names as reported by oracle,
+    /// followed by one line per row. Rows are fetched while writing them,
+    /// so the whole result is never held in memory. Values are written in
+    /// their textual representation (see the `FromSql<Text>` implementation
+    /// for `String`), `NULL` values are written as empty field. Fields
+    /// containing a comma, a double quote or a line break are quoted as
+    /// described in RFC 4180. Returns the number of written rows.
+    ///
+    /// ```no_run
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::OciConnection;
+    /// # table! { users { id -> Integer, name -> Text, } }
+    /// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+    /// let file = std::fs::File::create("users.csv").unwrap();
+    /// let rows = conn.export_csv(users::table, std::io::BufWriter::new(file))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Errors while writing to `out` are returned as
+    /// [`Error::SerializationError`].
+    pub fn export_csv<T, W>(&mut self, query: T, mut out: W) -> QueryResult<u64>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Oracle> + QueryId,
+        Oracle: QueryMetadata<T::SqlType>,
+        W: Write,
+    {
+        let write_error = |e: std::io::Error| Error::SerializationError(e.into());
+
+        let rows = LoadConnection::load(self, query)?;
+        let names = rows.column_names();
+        if !names.is_empty() {
+            write_record(&mut out, names.iter().map(|n| Some(n.as_str()))).map_err(write_error)?;
+        }
+
+        let mut count = 0;
+        for row in rows {
+            let row = row?;
+            let mut values = Vec::with_capacity(row.field_count());
+            for idx in 0..row.field_count() {
+                let field = row.get(idx);
+                let value = match field.as_ref().and_then(|f| f.value()) {
+                    Some(value) => Some(
+                        <String as FromSql<Text, Oracle>>::from_sql(value)
+                            .map_err(Error::DeserializationError)?,
+                    ),
+                    None => None,
+                };
+                values.push(value);
+            }
+            write_record(&mut out, values.iter().map(|v| v.as_deref())).map_err(write_error)?;
+            count += 1;
+        }
+        out.flush().map_err(write_error)?;
+        Ok(count)
+    }
+}
+
+fn write_record<'a>(
+    out: &mut impl Write,
+    fields: impl Iterator<Item = Option<&'a str>>,
+) -> std::io::Result<()> {
+    for (idx, field) in fields.enumerate() {
+        if idx > 0 {
+            out.write_all(b",")?;
+        }
+        match field {
+            Some(field) if field.contains([',', '"', '\n', '\r']) => {
+                write!(out, "\"{}\"", field.replace('"', "\"\""))?;
+            }
+            Some(field) => out.write_all(field.as_bytes())?,
+            None => {}
+        }
+    }
+    out.write_all(b"\r\n")
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use super::{ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+
+const MIGRATION_TABLE: &str = "__DIESEL_SCHEMA_MIGRATIONS";
+
+/// A difference between the existing migration table and the structure
+/// expected by diesel
+///
+/// See [`OciConnection::check_migration_table`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MigrationTableIssue {
+    /// The `__DIESEL_SCHEMA_MIGRATIONS` table does not exist
+    MissingTable,
+    /// A required column does not exist
+    MissingColumn {
+        /// The name of the missing column
+        column: &'static str,
+    },
+    /// A column has a type diesel cannot work with
+    UnexpectedType {
+        /// The name of the column
+        column: &'static str,
+        /// A description of the expected type
+        expected: &'static str,
+        /// The type of the existing column as reported by oracle
+        found: String,
+    },
+    /// A column that must not contain `NULL` values is nullable
+    Nullable {
+        /// The name of the column
+        column: &'static str,
+    },
+}
+
+impl fmt::Display for MigrationTableIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationTableIssue::MissingTable => {
+                write!(f, "The table `{}` does not exist", MIGRATION_TABLE)
+            }
+            MigrationTableIssue::MissingColumn { column } => write!(
+                f,
+                "The column `{}` is missing in `{}`",
+                column, MIGRATION_TABLE
+            ),
+            MigrationTableIssue::UnexpectedType {
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The column `{}` of `{}` has the type `{}`, expected {}",
+                column, MIGRATION_TABLE, found, expected
+            ),
+            MigrationTableIssue::Nullable { column } => write!(
+                f,
+                "The column `{}` of `{}` must be declared as `NOT NULL`",
+                column, MIGRATION_TABLE
+            ),
+        }
+    }
+}
+
+impl OciConnection {
+    /// Check if the migration table has the structure expected by diesel
+    ///
+    /// This compares the columns of the `__DIESEL_SCHEMA_MIGRATIONS` table
+    /// in the current schema with the ones created by
+    /// [`MigrationConnection::setup`](diesel::migration::MigrationConnection::setup)
+    /// and returns all differences found. An empty list means the table is fine.
+    /// This is meant to diagnose a table that was created manually or by an
+    /// older version of this crate, nothing is changed by this method.
+    pub fn check_migration_table(&mut self) -> QueryResult<Vec<MigrationTableIssue>> {
+        let columns = self
+            .raw
+            .query_as::<(String, String, String)>(
+                "SELECT column_name, data_type, nullable FROM user_tab_columns \
+                 WHERE table_name = :1",
+                &[&MIGRATION_TABLE],
+            )
+            .map_err(ErrorHelper::from)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ErrorHelper::from)?;
+
+        if columns.is_empty() {
+            return Ok(vec![MigrationTableIssue::MissingTable]);
+        }
+
+        let mut issues = Vec::new();
+        let mut check = |column: &'static str,
+                         expected: &'static str,
+                         type_ok: fn(&str) -> bool| {
+            match columns.iter().find(|(name, _, _)| name == column) {
+                None => issues.push(MigrationTableIssue::MissingColumn { column }),
+                Some((_, data_type, nullable)) => {
+                    if !type_ok(data_type) {
+                        issues.push(MigrationTableIssue::UnexpectedType {
+                            column,
+                            expected,
+                            found: data_type.clone(),
+                        });
+                    }
+                    if nullable == "Y" {
+                        issues.push(MigrationTableIssue::Nullable { column });
+                    }
+                }
+            }
+        };
+        check("VERSION", "a `VARCHAR2`", |t| {
+            t == "VARCHAR2" || t == "NVARCHAR2"
+        });
+        check("RUN_ON", "a `TIMESTAMP` or `DATE`", |t| {
+            t.starts_with("TIMESTAMP") || t == "DATE"
+        });
+        Ok(issues)
+    }
+}
@@ -0,0 +1,88 @@
+use super::{ErrorHelper, OciConnection};
+use diesel::result::{Error, QueryResult};
+
+impl OciConnection {
+    /// Look up the descriptor of a user defined object or collection type
+    ///
+    /// `name` is given as `TYPE`, `SCHEMA.TYPE` or, for types declared in
+    /// a package specification, as `PACKAGE.TYPE`. Looking up a type requires
+    /// a round trip to the database, so descriptors are cached by name for
+    /// the lifetime of the connection. See [`Object`](crate::Object) for
+    /// binding values of the returned type.
+    pub fn object_type(&mut self, name: &str) -> QueryResult<oracle::sql_type::ObjectType> {
+        if let Some(object_type) = self.object_types.get(name) {
+            return Ok(object_type.clone());
+        }
+        let object_type = self.raw.object_type(name).map_err(ErrorHelper::from)?;
+        self.object_types
+            .insert(name.to_owned(), object_type.clone());
+        Ok(object_type)
+    }
+
+    /// Forget all descriptors cached by [`object_type`](Self::object_type)
+    ///
+    /// This is required after a type was changed via `ALTER TYPE` or
+    /// recreated, otherwise values are still built from the old descriptor.
+    pub fn clear_object_type_cache(&mut self) {
+        self.object_types.clear();
+    }
+}
+
+// The out bind of a returned object needs its concrete object type, which
+// is not known from the sql type. It is taken from the description of a
+// query selecting the returned expressions from the modified table:
+//
+// INSERT INTO "SHAPES" ("ID", "ORIGIN") VALUES (:in0, :in1)
+//     RETURNING "SHAPES"."ORIGIN" INTO :out0
+//
+// is described as
+//
+// SELECT "SHAPES"."ORIGIN" FROM "SHAPES" WHERE 1 = 0
+//
+// The result contains one entry per returned value, `None` for values
+// that are not of an object type.
+pub(super) fn returned_object_types(
+    raw: &oracle::Connection,
+    sql: &str,
+) -> QueryResult<Vec<Option<oracle::sql_type::ObjectType>>> {
+    let (table, returned) = split_returning(sql).ok_or_else(|| {
+        Error::QueryBuilderError(
+            "Returning values of object types is only supported for \
+             `INSERT`, `UPDATE` and `DELETE` statements"
+                .into(),
+        )
+    })?;
+    let describe = format!("SELECT {} FROM {} WHERE 1 = 0", returned, table);
+    let mut stmt = raw
+        .statement(&describe)
+        .build()
+        .map_err(ErrorHelper::from)?;
+    let rows = stmt.query(&[]).map_err(ErrorHelper::from)?;
+    Ok(rows
+        .column_info()
+        .iter()
+        .map(|column| match column.oracle_type() {
+            oracle::sql_type::OracleType::Object(object_type) => Some(object_type.clone()),
+            _ => None,
+        })
+        .collect())
+}
+
+// Splits a statement into the modified table and the returned expressions
+fn split_returning(sql: &str) -> Option<(&str, &str)> {
+    let start = sql.rfind(" RETURNING ")? + " RETURNING ".len();
+    let returned = &sql[start..];
+    let returned = &returned[..returned.rfind(" INTO ")?];
+    let target = ["INSERT INTO ", "UPDATE ", "DELETE FROM "]
+        .iter()
+        .find_map(|prefix| sql.strip_prefix(prefix))?;
+    // the table ends at the first space outside of a quoted identifier
+    let mut quoted = false;
+    let end = target.find(|c| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        c == ' ' && !quoted
+    })?;
+    Some((&target[..end], returned))
+}
@@ -1,9 +1,11 @@
-use crate::oracle::types::OciDataType;
+use crate::oracle::types::{BoolTruthiness, NumericOverflowPolicy, OciDataType};
 
 /// A unserialized value as received from the database
 #[derive(Debug, Clone)]
 pub struct OracleValue<'a> {
     pub(crate) inner: InnerValue<'a>,
+    pub(crate) numeric_overflow_policy: NumericOverflowPolicy,
+    pub(crate) bool_truthiness: BoolTruthiness,
 }
 
 #[derive(Debug, Clone)]
@@ -12,42 +14,81 @@ pub(crate) enum InnerValue<'a> {
         raw_value: &'a oracle::SqlValue,
         tpe: oracle::sql_type::OracleType,
     },
-    SmallInt(i16),
-    Integer(i32),
-    BigInt(i64),
     Float(f32),
     Double(f64),
     Text(String),
     Binary(Vec<u8>),
+    /// The content of a `CLOB` returned via `RETURNING`
+    Clob(String),
+    /// The content of a `BLOB` returned via `RETURNING`
+    Blob(Vec<u8>),
+    /// The textual representation of a oracle `NUMBER`
+    Numeric(String),
+    IntervalYM(crate::oracle::types::YearMonthInterval),
+    IntervalDS(oracle::sql_type::IntervalDS),
+    /// A value of a user defined type returned via `RETURNING`
+    Object(oracle::sql_type::Object),
     #[cfg(feature = "chrono")]
     Date(chrono_time::NaiveDate),
     #[cfg(feature = "chrono")]
     Timestamp(chrono_time::NaiveDateTime),
+    #[cfg(feature = "chrono")]
+    TimestampTz(chrono_time::DateTime<chrono_time::FixedOffset>),
 }
 
 impl<'a> OracleValue<'a> {
-    pub(crate) fn new(raw_value: &'a oracle::SqlValue, tpe: oracle::sql_type::OracleType) -> Self {
+    pub(crate) fn new(
+        raw_value: &'a oracle::SqlValue,
+        tpe: oracle::sql_type::OracleType,
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+    ) -> Self {
         Self {
             inner: InnerValue::Raw { raw_value, tpe },
+            numeric_overflow_policy,
+            bool_truthiness,
+        }
+    }
+
+    pub(crate) fn from_inner(inner: InnerValue<'a>) -> Self {
+        Self {
+            inner,
+            numeric_overflow_policy: NumericOverflowPolicy::default(),
+            bool_truthiness: BoolTruthiness::default(),
         }
     }
 
+    pub(crate) fn with_settings(
+        mut self,
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+    ) -> Self {
+        self.numeric_overflow_policy = numeric_overflow_policy;
+        self.bool_truthiness = bool_truthiness;
+        self
+    }
+
     /// Get the datatype of the underlying value
     pub fn value_type(&self) -> OciDataType {
         use self::InnerValue::*;
 
         match self.inner {
-            SmallInt(_) => OciDataType::SmallInt,
-            Integer(_) => OciDataType::Integer,
-            BigInt(_) => OciDataType::BigInt,
             Float(_) => OciDataType::Float,
             Double(_) => OciDataType::Double,
             Text(_) => OciDataType::Text,
             Binary(_) => OciDataType::Binary,
+            Clob(_) => OciDataType::Clob,
+            Blob(_) => OciDataType::Blob,
+            Numeric(_) => OciDataType::Numeric,
+            IntervalYM(_) => OciDataType::IntervalYM,
+            IntervalDS(_) => OciDataType::IntervalDS,
+            Object(_) => OciDataType::Object,
             #[cfg(feature = "chrono")]
             Date(_) => OciDataType::Date,
             #[cfg(feature = "chrono")]
             Timestamp(_) => OciDataType::Timestamp,
+            #[cfg(feature = "chrono")]
+            TimestampTz(_) => OciDataType::TimestampTz,
             Raw {
                 tpe: oracle::sql_type::OracleType::Varchar2(_),
                 ..
@@ -100,6 +141,10 @@ impl<'a> OracleValue<'a> {
                 tpe: oracle::sql_type::OracleType::Timestamp(_),
                 ..
             } => OciDataType::Timestamp,
+            Raw {
+                tpe: oracle::sql_type::OracleType::TimestampTZ(_),
+                ..
+            } => OciDataType::TimestampTz,
             Raw {
                 tpe: oracle::sql_type::OracleType::CLOB,
                 ..
@@ -108,6 +153,22 @@ impl<'a> OracleValue<'a> {
                 tpe: oracle::sql_type::OracleType::BLOB,
                 ..
             } => OciDataType::Binary,
+            Raw {
+                tpe: oracle::sql_type::OracleType::Object(_),
+                ..
+            } => OciDataType::Object,
+            Raw {
+                tpe: oracle::sql_type::OracleType::Rowid,
+                ..
+            } => OciDataType::Rowid,
+            Raw {
+                tpe: oracle::sql_type::OracleType::IntervalDS(_, _),
+                ..
+            } => OciDataType::IntervalDS,
+            Raw {
+                tpe: oracle::sql_type::OracleType::IntervalYM(_),
+                ..
+            } => OciDataType::IntervalYM,
             Raw {
                 tpe: oracle::sql_type::OracleType::Int64,
                 ..
@@ -133,10 +194,6 @@ impl<'a> OracleValue<'a> {
                 tpe: oracle::sql_type::OracleType::Boolean,
                 ..
             }
-            | Raw {
-                tpe: oracle::sql_type::OracleType::Object(_),
-                ..
-            }
             | Raw {
                 tpe: oracle::sql_type::OracleType::Long,
                 ..
@@ -145,26 +202,10 @@ impl<'a> OracleValue<'a> {
                 tpe: oracle::sql_type::OracleType::LongRaw,
                 ..
             }
-            | Raw {
-                tpe: oracle::sql_type::OracleType::TimestampTZ(_),
-                ..
-            }
             | Raw {
                 tpe: oracle::sql_type::OracleType::TimestampLTZ(_),
                 ..
             }
-            | Raw {
-                tpe: oracle::sql_type::OracleType::IntervalDS(_, _),
-                ..
-            }
-            | Raw {
-                tpe: oracle::sql_type::OracleType::IntervalYM(_),
-                ..
-            }
-            | Raw {
-                tpe: oracle::sql_type::OracleType::Rowid,
-                ..
-            }
             | Raw {
                 tpe: oracle::sql_type::OracleType::Raw(_),
                 ..
@@ -1,13 +1,24 @@
+use std::io::BufReader;
 use std::rc::Rc;
 
 use crate::oracle::backend::Oracle;
+use crate::oracle::types::{BoolTruthiness, NumericOverflowPolicy};
+use diesel::result::Error;
 use diesel::row::{self, Row, RowGatWorkaround, RowIndex};
+use diesel::QueryResult;
 
 use super::oracle_value::OracleValue;
+use super::ErrorHelper;
 
+/// A single row of a result loaded via an
+/// [`OciConnection`](super::OciConnection)
 pub struct OciRow {
     row: InnerOciRow,
     column_infos: Rc<Vec<oracle::ColumnInfo>>,
+    column_names: Rc<[String]>,
+    numeric_overflow_policy: NumericOverflowPolicy,
+    bool_truthiness: BoolTruthiness,
+    lob_chunk_size: Option<usize>,
 }
 
 enum InnerOciRow {
@@ -16,17 +27,95 @@ enum InnerOciRow {
 }
 
 impl OciRow {
-    pub fn new(row: oracle::Row, column_infos: Rc<Vec<oracle::ColumnInfo>>) -> Self {
+    pub(super) fn new(
+        row: oracle::Row,
+        column_infos: Rc<Vec<oracle::ColumnInfo>>,
+        column_names: Rc<[String]>,
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+        lob_chunk_size: Option<usize>,
+    ) -> Self {
         OciRow {
             row: InnerOciRow::Row(row),
             column_infos,
+            column_names,
+            numeric_overflow_policy,
+            bool_truthiness,
+            lob_chunk_size,
         }
     }
 
-    pub fn new_from_value(values: Vec<Option<OracleValue<'static>>>) -> Self {
+    pub(super) fn new_from_value(values: Vec<Option<OracleValue<'static>>>) -> Self {
         Self {
             row: InnerOciRow::Values(values),
             column_infos: Rc::new(Vec::new()),
+            column_names: Rc::new([]),
+            numeric_overflow_policy: NumericOverflowPolicy::default(),
+            bool_truthiness: BoolTruthiness::default(),
+            lob_chunk_size: None,
+        }
+    }
+
+    /// The names of the columns, in the order of the projection
+    ///
+    /// The names are shared by all rows of a result. This is empty for
+    /// rows returned via a `RETURNING` clause, as oracle does not report
+    /// column names for returned values.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// The oracle type of the column at `idx`, as reported by oracle
+    ///
+    /// Returns `None` if there is no such column or for rows returned via a
+    /// `RETURNING` clause, see [`column_names`](Self::column_names).
+    pub fn column_oracle_type(&self, idx: usize) -> Option<&oracle::sql_type::OracleType> {
+        self.column_infos.get(idx).map(|c| c.oracle_type())
+    }
+
+    /// Read the `CLOB` column at `idx` as stream
+    ///
+    /// The returned reader fetches the content of the LOB in chunks while
+    /// reading, see
+    /// [`OciConnection::set_lob_chunk_size`](super::OciConnection::set_lob_chunk_size).
+    /// This requires the connection to fetch LOB locators, see
+    /// [`OciConnection::set_lob_locators`](super::OciConnection::set_lob_locators).
+    /// Returns `None` for a `NULL` value.
+    pub fn clob(&self, idx: usize) -> QueryResult<Option<BufReader<oracle::sql_type::Clob>>> {
+        self.lob(idx)
+    }
+
+    /// Read the `BLOB` column at `idx` as stream
+    ///
+    /// See [`clob`](Self::clob) for details.
+    pub fn blob(&self, idx: usize) -> QueryResult<Option<BufReader<oracle::sql_type::Blob>>> {
+        self.lob(idx)
+    }
+
+    fn lob<T>(&self, idx: usize) -> QueryResult<Option<BufReader<T>>>
+    where
+        T: oracle::sql_type::FromSql + oracle::sql_type::Lob + std::io::Read,
+    {
+        match &self.row {
+            InnerOciRow::Row(row) => {
+                let sql = row.sql_values().get(idx).ok_or_else(|| {
+                    Error::DeserializationError(format!("Column {} does not exist", idx).into())
+                })?;
+                if sql.is_null().map_err(ErrorHelper::from)? {
+                    return Ok(None);
+                }
+                let lob = sql.get::<T>().map_err(ErrorHelper::from)?;
+                let chunk_size = match self.lob_chunk_size {
+                    Some(chunk_size) => chunk_size,
+                    None => lob.chunk_size().map_err(ErrorHelper::from)?,
+                };
+                Ok(Some(BufReader::with_capacity(chunk_size, lob)))
+            }
+            // values returned via a `RETURNING` clause are received as out
+            // binds, which contain the content of the LOB instead of a locator
+            InnerOciRow::Values(_) => Err(Error::DeserializationError(
+                "LOB values returned via a `RETURNING` clause cannot be read as stream".into(),
+            )),
         }
     }
 }
@@ -41,13 +130,12 @@ impl RowIndex<usize> for OciRow {
     }
 }
 
+// A result can contain several columns with the same name, for example
+// `SELECT a.id, b.id FROM …`. A name always refers to the first of these
+// columns, the others are only accessible by their position.
 impl<'a> RowIndex<&'a str> for OciRow {
     fn idx(&self, field_name: &'a str) -> Option<usize> {
-        self.column_infos
-            .iter()
-            .enumerate()
-            .find(|(_, c)| c.name() == field_name)
-            .map(|(idx, _)| idx)
+        self.column_names.iter().position(|name| name == field_name)
     }
 }
 
@@ -69,7 +157,12 @@ impl<'a> Row<'a, Oracle> for OciRow {
     {
         let idx = self.idx(idx)?;
         Some(OciField {
-            field_value: self.row.value_at(idx, &self.column_infos),
+            field_value: self.row.value_at(
+                idx,
+                &self.column_infos,
+                self.numeric_overflow_policy,
+                self.bool_truthiness,
+            ),
             column_info: self.column_infos.get(idx),
         })
     }
@@ -102,7 +195,13 @@ impl<'a> row::Field<'a, Oracle> for OciField<'a> {
 }
 
 impl InnerOciRow {
-    fn value_at(&self, idx: usize, col_infos: &[oracle::ColumnInfo]) -> Option<OracleValue<'_>> {
+    fn value_at(
+        &self,
+        idx: usize,
+        col_infos: &[oracle::ColumnInfo],
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+    ) -> Option<OracleValue<'_>> {
         match self {
             InnerOciRow::Row(row) => {
                 let sql = &row.sql_values()[idx];
@@ -110,7 +209,12 @@ impl InnerOciRow {
                     None
                 } else {
                     let tpe = col_infos[idx].oracle_type().clone();
-                    Some(OracleValue::new(sql, tpe))
+                    Some(OracleValue::new(
+                        sql,
+                        tpe,
+                        numeric_overflow_policy,
+                        bool_truthiness,
+                    ))
                 }
             }
             InnerOciRow::Values(ref v) => v[idx].clone(),
@@ -0,0 +1,63 @@
+use super::{ErrorHelper, OciConnection};
+use crate::oracle::Oracle;
+use diesel::query_builder::{QueryBuilder, QueryFragment};
+use diesel::result::QueryResult;
+use diesel::{QuerySource, Table};
+
+impl OciConnection {
+    /// Delete the rows of `table` with the given `ROWID`s
+    ///
+    /// The rowids are bound as array to `DELETE FROM table WHERE ROWID = :in0`,
+    /// so all rows are deleted in a single round trip, independent of the
+    /// number of rowids. This is the fastest way to delete a set of rows
+    /// captured before, for example via [`rowid`](crate::query_builder::rowid):
+    ///
+    /// ```no_run
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::OciConnection;
+    /// # use diesel_oci::query_builder::rowid;
+    /// # table! { users { id -> BigInt, name -> Text, } }
+    /// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+    /// let rowids = users::table
+    ///     .select(rowid())
+    ///     .filter(users::name.like("test%"))
+    ///     .load::<String>(conn)?;
+    /// let deleted = conn.delete_by_rowids(users::table, &rowids)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns the number of deleted rows. Rowids of rows that do not exist
+    /// anymore are ignored, invalid rowids fail with `ORA-01410`.
+    pub fn delete_by_rowids<T, R>(&mut self, table: T, rowids: &[R]) -> QueryResult<usize>
+    where
+        T: Table,
+        T::FromClause: QueryFragment<Oracle>,
+        R: AsRef<str>,
+    {
+        if rowids.is_empty() {
+            return Ok(0);
+        }
+        let mut qb = self.query_builder();
+        qb.push_sql("DELETE FROM ");
+        table.from_clause().to_sql(&mut qb, &Oracle)?;
+        qb.push_sql(" WHERE ROWID = ");
+        qb.push_bind_param();
+        let sql = qb.finish();
+
+        let mut batch = self
+            .raw
+            .batch(&sql, rowids.len())
+            .with_row_counts()
+            .build()
+            .map_err(ErrorHelper::from)?;
+        for rowid in rowids {
+            batch
+                .append_row(&[&rowid.as_ref()])
+                .map_err(ErrorHelper::from)?;
+        }
+        batch.execute().map_err(ErrorHelper::from)?;
+        let row_counts = batch.row_counts().map_err(ErrorHelper::from)?;
+        Ok(row_counts.into_iter().sum::<u64>() as usize)
+    }
+}
@@ -0,0 +1,315 @@
+use super::{add_error_details, ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+use oracle::sql_type::OracleType;
+
+/// A result set returned from a script via `DBMS_SQL.RETURN_RESULT`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptResultSet {
+    /// The names of the returned columns
+    pub column_names: Vec<String>,
+    /// The returned rows, each value converted to its string representation
+    pub rows: Vec<Vec<Option<String>>>,
+}
+
+/// The output produced by [`OciConnection::execute_script`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptOutput {
+    /// All result sets returned via `DBMS_SQL.RETURN_RESULT`, in order
+    pub result_sets: Vec<ScriptResultSet>,
+    /// All lines written via `DBMS_OUTPUT`
+    pub dbms_output: Vec<String>,
+}
+
+impl OciConnection {
+    /// Execute a sql script, capturing its output
+    ///
+    /// In contrast to [`batch_execute`](diesel::connection::SimpleConnection::batch_execute),
+    /// `script` is not split into single statements, but sent to oracle as
+    /// it is, so it has to be a single sql statement or PL/SQL block. All
+    /// result sets returned via `DBMS_SQL.RETURN_RESULT` and all lines
+    /// written via `DBMS_OUTPUT` are collected. This is mostly useful for
+    /// migration or diagnostic scripts that report their progress.
+    pub fn execute_script(&mut self, script: &str) -> QueryResult<ScriptOutput> {
+        self.raw
+            .execute("BEGIN DBMS_OUTPUT.ENABLE(NULL); END;", &[])
+            .map_err(ErrorHelper::from)?;
+        let res = self.execute_script_and_collect_output(script);
+        // disabling also clears any output that was not read yet
+        let disable = self
+            .raw
+            .execute("BEGIN DBMS_OUTPUT.DISABLE; END;", &[])
+            .map_err(ErrorHelper::from);
+        let output = res?;
+        disable?;
+        Ok(output)
+    }
+
+    fn execute_script_and_collect_output(&mut self, script: &str) -> QueryResult<ScriptOutput> {
+        let mut stmt = self
+            .raw
+            .statement(script)
+            .build()
+            .map_err(ErrorHelper::from)?;
+        stmt.execute(&[]).map_err(ErrorHelper::from)?;
+
+        let mut result_sets = Vec::new();
+        while let Some(mut cursor) = stmt.implicit_result().map_err(ErrorHelper::from)? {
+            let result_set = cursor.query().map_err(ErrorHelper::from)?;
+            let column_names = result_set
+                .column_info()
+                .iter()
+                .map(|c| c.name().to_owned())
+                .collect();
+            let rows = result_set
+                .map(|row| {
+                    row?.sql_values()
+                        .iter()
+                        .map(|v| v.get::<Option<String>>())
+                        .collect::<oracle::Result<Vec<_>>>()
+                })
+                .collect::<oracle::Result<Vec<_>>>()
+                .map_err(ErrorHelper::from)?;
+            result_sets.push(ScriptResultSet { column_names, rows });
+        }
+
+        let mut dbms_output = Vec::new();
+        let mut stmt = self
+            .raw
+            .statement("BEGIN DBMS_OUTPUT.GET_LINE(:line, :status); END;")
+            .build()
+            .map_err(ErrorHelper::from)?;
+        loop {
+            stmt.execute(&[&OracleType::Varchar2(32767), &OracleType::Number(0, 0)])
+                .map_err(ErrorHelper::from)?;
+            let status = stmt.bind_value::<_, i32>(2).map_err(ErrorHelper::from)?;
+            if status != 0 {
+                break;
+            }
+            let line = stmt
+                .bind_value::<_, Option<String>>(1)
+                .map_err(ErrorHelper::from)?;
+            dbms_output.push(line.unwrap_or_default());
+        }
+
+        Ok(ScriptOutput {
+            result_sets,
+            dbms_output,
+        })
+    }
+}
+
+impl OciConnection {
+    /// Execute a script with multiple statements one by one
+    ///
+    /// If a statement fails, the error keeps its kind and oracle error code.
+    /// The first line of its [`details`](diesel::result::DatabaseErrorInformation::details)
+    /// names the failed statement: `statement 3 of the script: INSERT INTO …`.
+    pub(super) fn execute_batch(&mut self, script: &str) -> QueryResult<()> {
+        let statements = split_script(script);
+        if let [statement] = &statements[..] {
+            self.raw
+                .execute(statement, &[])
+                .map_err(ErrorHelper::from)?;
+            return Ok(());
+        }
+        for (idx, statement) in statements.into_iter().enumerate() {
+            self.raw.execute(statement, &[]).map_err(|e| {
+                add_error_details(
+                    ErrorHelper::from(e).into(),
+                    &format!(
+                        "statement {} of the script: {}",
+                        idx + 1,
+                        snippet(statement)
+                    ),
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// The statement on a single line, shortened to a length suitable for an
+// error message
+fn snippet(statement: &str) -> String {
+    const MAX_SNIPPET_LEN: usize = 80;
+
+    let mut snippet = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Some((idx, _)) = snippet.char_indices().nth(MAX_SNIPPET_LEN) {
+        snippet.truncate(idx);
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// Split a sql script into single statements
+///
+/// This follows the rules of SQL*Plus: sql statements are terminated by a
+/// `;`, PL/SQL blocks (anonymous blocks and `CREATE` statements for
+/// procedures, functions, packages, triggers and types) are terminated by
+/// a line that only contains a `/`. Terminators inside of comments, string
+/// literals and quoted identifiers are ignored, as are statements that only
+/// consist of comments.
+fn split_script(script: &str) -> Vec<&str> {
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut has_code = false;
+    let mut is_plsql = false;
+    let mut line_is_blank = true;
+    let mut i = 0;
+
+    let mut finish = |start: usize, end: usize, has_code: bool| {
+        let statement = script[start..end].trim();
+        if has_code && !statement.is_empty() {
+            statements.push(statement);
+        }
+    };
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        match c {
+            b'\n' => {
+                line_is_blank = true;
+                i += 1;
+                continue;
+            }
+            b'/' if line_is_blank && rest_of_line_is_blank(&bytes[i + 1..]) => {
+                finish(start, i, has_code);
+                i = bytes[i..]
+                    .iter()
+                    .position(|b| *b == b'\n')
+                    .map_or(bytes.len(), |p| i + p);
+                start = i;
+                has_code = false;
+                is_plsql = false;
+                continue;
+            }
+            b'-' if next == Some(b'-') => {
+                i = bytes[i..]
+                    .iter()
+                    .position(|b| *b == b'\n')
+                    .map_or(bytes.len(), |p| i + p);
+                continue;
+            }
+            b'/' if next == Some(b'*') => {
+                i = find(bytes, i + 2, b"*/").map_or(bytes.len(), |p| p + 2);
+                line_is_blank = false;
+                continue;
+            }
+            c if c.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        line_is_blank = false;
+        if !has_code {
+            has_code = true;
+            is_plsql = starts_plsql_block(&script[i..]);
+        }
+        match c {
+            b'\'' => {
+                i = skip_string_literal(bytes, i);
+            }
+            b'"' => {
+                i = find(bytes, i + 1, b"\"").map_or(bytes.len(), |p| p + 1);
+            }
+            b';' if !is_plsql => {
+                finish(start, i, has_code);
+                i += 1;
+                start = i;
+                has_code = false;
+            }
+            _ => i += 1,
+        }
+    }
+    finish(start, bytes.len(), has_code);
+    statements
+}
+
+fn rest_of_line_is_blank(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .take_while(|b| **b != b'\n')
+        .all(|b| b.is_ascii_whitespace())
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|p| p + from)
+}
+
+/// Returns the position after the string literal starting at `start`
+fn skip_string_literal(bytes: &[u8], start: usize) -> usize {
+    // q'[…]' style literals, the quote is preceded by `q` or `nq`
+    let is_q_quote = start > 0
+        && bytes[start - 1].eq_ignore_ascii_case(&b'q')
+        && (start == 1
+            || !bytes[start - 2].is_ascii_alphanumeric()
+            || (bytes[start - 2].eq_ignore_ascii_case(&b'n')
+                && (start == 2 || !bytes[start - 3].is_ascii_alphanumeric())));
+    if is_q_quote {
+        if let Some(&open) = bytes.get(start + 1) {
+            let close = match open {
+                b'[' => b']',
+                b'{' => b'}',
+                b'<' => b'>',
+                b'(' => b')',
+                c => c,
+            };
+            return find(bytes, start + 2, &[close, b'\'']).map_or(bytes.len(), |p| p + 2);
+        }
+    }
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == b'\'' {
+            // a doubled quote is an escaped quote
+            if bytes.get(i + 1) == Some(&b'\'') {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+fn starts_plsql_block(statement: &str) -> bool {
+    let mut words = statement
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_uppercase());
+    match words.next().as_deref() {
+        Some("BEGIN") | Some("DECLARE") => true,
+        Some("CREATE") => {
+            let mut word = words.next();
+            if word.as_deref() == Some("OR") {
+                // OR REPLACE
+                words.next();
+                word = words.next();
+            }
+            if matches!(
+                word.as_deref(),
+                Some("EDITIONABLE") | Some("NONEDITIONABLE")
+            ) {
+                word = words.next();
+            }
+            matches!(
+                word.as_deref(),
+                Some("PROCEDURE")
+                    | Some("FUNCTION")
+                    | Some("PACKAGE")
+                    | Some("TRIGGER")
+                    | Some("TYPE")
+                    | Some("LIBRARY")
+            )
+        }
+        _ => false,
+    }
+}
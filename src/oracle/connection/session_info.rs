@@ -0,0 +1,38 @@
+use super::{ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+
+// The values are sent to the database with the next round trip, they
+// show up in the `CLIENT_IDENTIFIER`, `MODULE` and `ACTION` columns of
+// `V$SESSION` and in the `USERENV` context.
+impl OciConnection {
+    /// Set the client identifier of the current session
+    ///
+    /// This is usually the name of the end user the application acts for,
+    /// which allows to trace sessions of a connection pool back to the user.
+    /// The client identifier can be set via the `client_identifier` query
+    /// parameter of the connection url as well.
+    pub fn set_client_identifier(&mut self, client_identifier: &str) -> QueryResult<()> {
+        self.raw
+            .set_client_identifier(client_identifier)
+            .map_err(ErrorHelper::from)?;
+        Ok(())
+    }
+
+    /// Set the name of the module running in the current session
+    ///
+    /// The module can be set via the `module` query parameter of the
+    /// connection url as well.
+    pub fn set_module(&mut self, module: &str) -> QueryResult<()> {
+        self.raw.set_module(module).map_err(ErrorHelper::from)?;
+        Ok(())
+    }
+
+    /// Set the name of the action the current module performs
+    ///
+    /// The action can be set via the `action` query parameter of the
+    /// connection url as well.
+    pub fn set_action(&mut self, action: &str) -> QueryResult<()> {
+        self.raw.set_action(action).map_err(ErrorHelper::from)?;
+        Ok(())
+    }
+}
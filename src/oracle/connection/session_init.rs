@@ -0,0 +1,72 @@
+use super::OciConnection;
+use diesel::connection::SimpleConnection;
+use diesel::result::QueryResult;
+
+impl OciConnection {
+    /// Execute the given statements to set up the session
+    ///
+    /// This is meant for statements like `ALTER SESSION SET …`, that
+    /// configure the session for all following queries. The statements are
+    /// executed in order, the first failing statement stops the execution
+    /// and its error is returned. With the `r2d2` feature, `SessionInit`
+    /// runs the statements for each connection of a pool.
+    pub fn run_session_init(&mut self, sql: &[&str]) -> QueryResult<()> {
+        for statement in sql {
+            self.batch_execute(statement)?;
+        }
+        Ok(())
+    }
+}
+
+/// A connection customizer for `r2d2` pools running statements to set up
+/// the session of each new connection
+///
+/// ```no_run
+/// # use diesel::r2d2::{ConnectionManager, Pool};
+/// # use diesel_oci::{OciConnection, SessionInit};
+/// # fn run_test(database_url: &str) -> Result<(), diesel::r2d2::PoolError> {
+/// let pool = Pool::builder()
+///     .connection_customizer(Box::new(SessionInit::new([
+///         "ALTER SESSION SET NLS_DATE_FORMAT = 'YYYY-MM-DD'",
+///         "ALTER SESSION SET TIME_ZONE = 'UTC'",
+///     ])))
+///     .build(ConnectionManager::<OciConnection>::new(database_url))?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The statements are run via [`OciConnection::run_session_init`] when
+/// the pool establishes a connection, not each time a connection is
+/// checked out. A failing statement discards the new connection.
+#[cfg(feature = "r2d2")]
+#[derive(Debug, Clone)]
+pub struct SessionInit {
+    statements: Vec<String>,
+}
+
+#[cfg(feature = "r2d2")]
+impl SessionInit {
+    /// Create a customizer running the given statements
+    pub fn new<I, S>(statements: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            statements: statements.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "r2d2")]
+impl diesel::r2d2::CustomizeConnection<OciConnection, diesel::r2d2::Error> for SessionInit {
+    fn on_acquire(&self, conn: &mut OciConnection) -> Result<(), diesel::r2d2::Error> {
+        let statements = self
+            .statements
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>();
+        conn.run_session_init(&statements)
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
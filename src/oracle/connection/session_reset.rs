@@ -0,0 +1,48 @@
+use super::transaction::OCITransactionManager;
+use super::{ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+
+impl OciConnection {
+    /// Reset the state of the session, to reuse the connection outside of
+    /// a connection pool
+    ///
+    /// This rolls back any open transaction, including a test transaction,
+    /// and discards all lines written via `DBMS_OUTPUT` that were not read
+    /// yet. `DBMS_OUTPUT` is disabled afterwards, which is the default for
+    /// new sessions. The state of PL/SQL packages is kept, see
+    /// [`reset_session_and_package_state`](Self::reset_session_and_package_state)
+    /// to reset it as well.
+    ///
+    /// The transaction manager is usable afterwards even if it was in an
+    /// error state before.
+    pub fn reset_session(&mut self) -> QueryResult<()> {
+        // the transaction is rolled back even if the transaction manager
+        // lost track of it, so the rollback is not skipped on errors
+        let rollback = self.raw.rollback().map_err(ErrorHelper::from);
+        self.raw.set_autocommit(true);
+        self.transaction_manager = OCITransactionManager::new();
+        rollback?;
+        // disabling also clears any output that was not read yet
+        self.raw
+            .execute("BEGIN DBMS_OUTPUT.DISABLE; END;", &[])
+            .map_err(ErrorHelper::from)?;
+        Ok(())
+    }
+
+    /// Reset the state of the session like
+    /// [`reset_session`](Self::reset_session), and additionally reinitialize
+    /// all PL/SQL packages via `DBMS_SESSION.MODIFY_PACKAGE_STATE`
+    ///
+    /// Package variables get their initial values again the next time a
+    /// package is used, as if it was used for the first time in this session.
+    pub fn reset_session_and_package_state(&mut self) -> QueryResult<()> {
+        self.reset_session()?;
+        self.raw
+            .execute(
+                "BEGIN DBMS_SESSION.MODIFY_PACKAGE_STATE(DBMS_SESSION.REINITIALIZE); END;",
+                &[],
+            )
+            .map_err(ErrorHelper::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use super::{ErrorHelper, OciConnection};
+use diesel::result::QueryResult;
+
+/// Selected statistics of the current session, as reported by `V$MYSTAT`
+///
+/// All values are cumulative since the session was established. Use
+/// [`SessionStatistics::since`] to get the cost of the statements executed
+/// between two snapshots.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionStatistics {
+    /// The number of logical reads (`session logical reads`)
+    pub logical_reads: u64,
+    /// The number of physical reads (`physical reads`)
+    pub physical_reads: u64,
+    /// The number of parse calls (`parse count (total)`)
+    pub parse_count_total: u64,
+    /// The number of hard parses (`parse count (hard)`)
+    pub parse_count_hard: u64,
+    /// The number of executed statements (`execute count`)
+    pub execute_count: u64,
+}
+
+impl SessionStatistics {
+    /// The difference between this snapshot and an `earlier` one
+    pub fn since(&self, earlier: &SessionStatistics) -> SessionStatistics {
+        SessionStatistics {
+            logical_reads: self.logical_reads.saturating_sub(earlier.logical_reads),
+            physical_reads: self.physical_reads.saturating_sub(earlier.physical_reads),
+            parse_count_total: self
+                .parse_count_total
+                .saturating_sub(earlier.parse_count_total),
+            parse_count_hard: self
+                .parse_count_hard
+                .saturating_sub(earlier.parse_count_hard),
+            execute_count: self.execute_count.saturating_sub(earlier.execute_count),
+        }
+    }
+}
+
+impl OciConnection {
+    /// Read the statistics of the current session
+    ///
+    /// This requires the `SELECT` privilege on `V$MYSTAT` and `V$STATNAME`
+    /// (for example via the `SELECT_CATALOG_ROLE`). Reading the statistics
+    /// is a query on its own, so it is included in the execute and parse
+    /// counts of the next snapshot.
+    ///
+    /// ```no_run
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::OciConnection;
+    /// # table! { users { id -> Integer, name -> Text, } }
+    /// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+    /// let before = conn.session_statistics()?;
+    /// let names = users::table.select(users::name).load::<String>(conn)?;
+    /// let cost = conn.session_statistics()?.since(&before);
+    /// println!("{} logical reads", cost.logical_reads);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn session_statistics(&mut self) -> QueryResult<SessionStatistics> {
+        let rows = self
+            .raw
+            .query_as::<(String, i64)>(
+                "SELECT n.name, s.value FROM v$mystat s \
+                 JOIN v$statname n ON n.statistic# = s.statistic# \
+                 WHERE n.name IN ('session logical reads', 'physical reads', \
+                 'parse count (total)', 'parse count (hard)', 'execute count')",
+                &[],
+            )
+            .map_err(ErrorHelper::from)?;
+
+        let mut stats = SessionStatistics::default();
+        for row in rows {
+            let (name, value) = row.map_err(ErrorHelper::from)?;
+            let value = u64::try_from(value).unwrap_or_default();
+            match &name as &str {
+                "session logical reads" => stats.logical_reads = value,
+                "physical reads" => stats.physical_reads = value,
+                "parse count (total)" => stats.parse_count_total = value,
+                "parse count (hard)" => stats.parse_count_hard = value,
+                "execute count" => stats.execute_count = value,
+                _ => {}
+            }
+        }
+        Ok(stats)
+    }
+}
@@ -1,22 +1,114 @@
+use std::rc::Rc;
+
 use diesel::QueryResult;
 
 use super::row::OciRow;
+use super::ErrorHelper;
+use crate::oracle::{BoolTruthiness, NumericOverflowPolicy};
+
+/// The cursor returned by loading a query via an
+/// [`OciConnection`](super::OciConnection)
+///
+/// Rows of a query are fetched lazily while iterating, so the cursor
+/// borrows the connection until it is dropped.
+pub struct RowIter<'conn> {
+    rows: Rows<'conn>,
+    column_count: usize,
+    // the names of the columns, shared by all rows
+    column_names: Rc<[String]>,
+}
 
-pub struct RowIter {
-    rows: Vec<OciRow>,
+enum Rows<'conn> {
+    // rows of a `SELECT` statement, fetched from the database on demand
+    Streaming {
+        result_set: oracle::ResultSet<'conn, oracle::Row>,
+        column_infos: Rc<Vec<oracle::ColumnInfo>>,
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+        lob_chunk_size: Option<usize>,
+    },
+    // values returned via a `RETURNING` clause, which are
+    // all received at once as out binds
+    Buffered(std::vec::IntoIter<OciRow>),
 }
 
-impl RowIter {
-    pub(super) fn new(mut rows: Vec<OciRow>) -> Self {
-        rows.reverse();
-        Self { rows }
+impl<'conn> RowIter<'conn> {
+    pub(super) fn new(
+        result_set: oracle::ResultSet<'conn, oracle::Row>,
+        numeric_overflow_policy: NumericOverflowPolicy,
+        bool_truthiness: BoolTruthiness,
+        lob_chunk_size: Option<usize>,
+    ) -> Self {
+        let column_infos = Rc::new(result_set.column_info().to_owned());
+        let column_names = column_infos.iter().map(|c| c.name().to_owned()).collect();
+        Self {
+            column_count: column_infos.len(),
+            rows: Rows::Streaming {
+                result_set,
+                column_infos,
+                numeric_overflow_policy,
+                bool_truthiness,
+                lob_chunk_size,
+            },
+            column_names,
+        }
+    }
+
+    pub(super) fn new_without_column_infos(rows: Vec<OciRow>, column_count: usize) -> Self {
+        Self {
+            rows: Rows::Buffered(rows.into_iter()),
+            column_count,
+            column_names: Rc::new([]),
+        }
+    }
+
+    /// The number of columns of each row of the result
+    ///
+    /// This is known before iterating, even if the result is empty.
+    pub fn column_count(&self) -> usize {
+        self.column_count
+    }
+
+    /// The names of the columns of the result, in the order of the projection
+    ///
+    /// The names are shared with the rows of the result, see
+    /// [`OciRow::column_names`]. This is empty for the result of an
+    /// `INSERT … RETURNING` statement, as oracle does not report column
+    /// names for returned values.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
     }
 }
 
-impl Iterator for RowIter {
+impl<'conn> Iterator for RowIter<'conn> {
     type Item = QueryResult<OciRow>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.rows.pop().map(Ok)
+        match &mut self.rows {
+            Rows::Streaming {
+                result_set,
+                column_infos,
+                numeric_overflow_policy,
+                bool_truthiness,
+                lob_chunk_size,
+            } => {
+                let column_infos = column_infos.clone();
+                let column_names = self.column_names.clone();
+                let numeric_overflow_policy = *numeric_overflow_policy;
+                let bool_truthiness = *bool_truthiness;
+                let lob_chunk_size = *lob_chunk_size;
+                result_set.next().map(|row| {
+                    Ok(OciRow::new(
+                        row.map_err(ErrorHelper)?,
+                        column_infos,
+                        column_names,
+                        numeric_overflow_policy,
+                        bool_truthiness,
+                        lob_chunk_size,
+                    ))
+                })
+            }
+            Rows::Buffered(rows) => rows.next().map(Ok),
+        }
     }
 }
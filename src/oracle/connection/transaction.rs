@@ -1,4 +1,5 @@
 use std::num::NonZeroU32;
+use std::ops::{Deref, DerefMut};
 
 use super::ErrorHelper;
 use super::OciConnection;
@@ -6,7 +7,126 @@ use diesel::connection::SimpleConnection;
 use diesel::connection::TransactionDepthChange;
 use diesel::connection::TransactionManager;
 use diesel::connection::TransactionManagerStatus;
-use diesel::result::QueryResult;
+use diesel::result::{DatabaseErrorKind, Error, QueryResult};
+
+/// The isolation level of a transaction
+///
+/// See [`OciConnection::transaction_with_isolation_level`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    /// Each statement sees all data committed before it started (the default)
+    ReadCommitted,
+    /// The transaction sees only data committed before it started
+    ///
+    /// Modifying a row that was changed by another transaction committed
+    /// after this transaction began fails with `ORA-08177`, which is reported as
+    /// [`DatabaseErrorKind::SerializationFailure`](diesel::result::DatabaseErrorKind::SerializationFailure)
+    Serializable,
+    /// Like `Serializable`, but the transaction is not allowed to modify any data
+    ReadOnly,
+}
+
+impl IsolationLevel {
+    fn set_transaction_sql(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadCommitted => "SET TRANSACTION ISOLATION LEVEL READ COMMITTED",
+            IsolationLevel::Serializable => "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE",
+            IsolationLevel::ReadOnly => "SET TRANSACTION READ ONLY",
+        }
+    }
+}
+
+/// A builder for transactions with a specific isolation level
+///
+/// See [`OciConnection::build_transaction`]
+#[allow(missing_debug_implementations)]
+#[must_use = "Transaction builder does nothing unless you call `run` on it"]
+pub struct OciTransactionBuilder<'a> {
+    conn: &'a mut OciConnection,
+    isolation_level: Option<IsolationLevel>,
+}
+
+impl<'a> OciTransactionBuilder<'a> {
+    /// Run the transaction with `SERIALIZABLE` isolation
+    ///
+    /// See [`IsolationLevel::Serializable`]
+    pub fn serializable(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::Serializable);
+        self
+    }
+
+    /// Run the transaction with `READ COMMITTED` isolation
+    ///
+    /// See [`IsolationLevel::ReadCommitted`]
+    pub fn read_committed(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::ReadCommitted);
+        self
+    }
+
+    /// Run the transaction as `READ ONLY` transaction
+    ///
+    /// See [`IsolationLevel::ReadOnly`]
+    pub fn read_only(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::ReadOnly);
+        self
+    }
+
+    /// Run `f` in the configured transaction
+    ///
+    /// The transaction is committed if `f` returns `Ok` and rolled back
+    /// otherwise. If an isolation level was chosen, the corresponding
+    /// `SET TRANSACTION` statement is issued before `f` is called, which
+    /// fails with [`Error::AlreadyInTransaction`] inside of another
+    /// transaction. Without isolation level this behaves like
+    /// [`Connection::transaction`](diesel::Connection::transaction).
+    pub fn run<T, E, F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut OciConnection) -> Result<T, E>,
+        E: From<Error>,
+    {
+        match self.isolation_level {
+            Some(level) => self.conn.transaction_with_isolation_level(level, f),
+            None => diesel::Connection::transaction(self.conn, f),
+        }
+    }
+}
+
+/// A savepoint inside of a transaction, see [`OciConnection::checkpoint`]
+///
+/// The checkpoint borrows the connection, which is accessible through it
+/// via `Deref`. Dropping it keeps all changes made since the checkpoint
+/// was taken, they are committed or rolled back with the transaction.
+#[allow(missing_debug_implementations)]
+#[must_use = "Dropping a checkpoint keeps the changes, so it is useless unless `rollback` is called"]
+pub struct Checkpoint<'a> {
+    conn: &'a mut OciConnection,
+    name: String,
+}
+
+impl<'a> Checkpoint<'a> {
+    /// Roll back all changes made since the checkpoint was taken
+    ///
+    /// Changes made before the checkpoint are kept, and the transaction
+    /// stays open.
+    pub fn rollback(self) -> QueryResult<()> {
+        self.conn
+            .batch_execute(&format!("ROLLBACK TO SAVEPOINT {}", self.name))
+    }
+}
+
+impl<'a> Deref for Checkpoint<'a> {
+    type Target = OciConnection;
+
+    fn deref(&self) -> &OciConnection {
+        self.conn
+    }
+}
+
+impl<'a> DerefMut for Checkpoint<'a> {
+    fn deref_mut(&mut self) -> &mut OciConnection {
+        self.conn
+    }
+}
 
 /// An implementation of `TransactionManager` which can be used for oracle
 #[allow(missing_debug_implementations)]
@@ -39,12 +159,15 @@ impl OCITransactionManager {
     /// This is used by connections to implement more complex transaction APIs
     /// to set things such as isolation levels.
     /// Returns an error if already inside of a transaction.
-    #[allow(dead_code)]
     pub fn begin_transaction_sql(conn: &mut OciConnection, sql: &str) -> QueryResult<()> {
         use diesel::result::Error::AlreadyInTransaction;
 
         if Self::get_transaction_depth(conn)?.is_none() {
-            conn.batch_execute(sql)?;
+            conn.raw.set_autocommit(false);
+            if let Err(e) = conn.batch_execute(sql) {
+                conn.raw.set_autocommit(true);
+                return Err(e);
+            }
             Self::change_transaction_depth(conn, TransactionDepthChange::IncreaseDepth)
         } else {
             Err(AlreadyInTransaction)
@@ -133,3 +256,188 @@ impl TransactionManager<OciConnection> for OCITransactionManager {
         &mut conn.transaction_manager.status
     }
 }
+
+impl OciConnection {
+    /// Run `f` in a new transaction with the given isolation level
+    ///
+    /// The transaction is committed if `f` returns `Ok` and rolled back
+    /// otherwise, like it is done by [`Connection::transaction`](diesel::Connection::transaction).
+    /// Oracle only allows to set the isolation level at the start of a transaction,
+    /// so this returns an error if the connection is already inside of a transaction.
+    pub fn transaction_with_isolation_level<T, E, F>(
+        &mut self,
+        level: IsolationLevel,
+        f: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        OCITransactionManager::begin_transaction_sql(self, level.set_transaction_sql())?;
+        match f(&mut *self) {
+            Ok(value) => {
+                OCITransactionManager::commit_transaction(self)?;
+                Ok(value)
+            }
+            Err(user_error) => match OCITransactionManager::rollback_transaction(self) {
+                Ok(()) | Err(Error::BrokenTransactionManager) => Err(user_error),
+                Err(rollback_error) => Err(rollback_error.into()),
+            },
+        }
+    }
+
+    /// Take a checkpoint to roll back the changes made after it, without
+    /// rolling back the whole transaction
+    ///
+    /// The checkpoint is a savepoint with a name unique for this connection,
+    /// so checkpoints can be nested freely. Statements are executed through
+    /// the returned guard:
+    ///
+    /// ```no_run
+    /// # use diesel_oci::OciConnection;
+    /// # use diesel::prelude::*;
+    /// # fn run_test(conn: &mut OciConnection) -> diesel::QueryResult<()> {
+    /// conn.transaction(|conn| {
+    ///     diesel::sql_query("INSERT INTO orders (id) VALUES (1)").execute(conn)?;
+    ///     let mut checkpoint = conn.checkpoint()?;
+    ///     let ret = diesel::sql_query("INSERT INTO orders (id) VALUES (2)")
+    ///         .execute(&mut *checkpoint);
+    ///     if ret.is_err() {
+    ///         // only the second insert is rolled back
+    ///         checkpoint.rollback()?;
+    ///     }
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    ///
+    /// Returns [`Error::NotInTransaction`] outside of a transaction, as the
+    /// changes are committed immediately then.
+    pub fn checkpoint(&mut self) -> QueryResult<Checkpoint<'_>> {
+        if OCITransactionManager::get_transaction_depth(self)?.is_none() {
+            return Err(Error::NotInTransaction);
+        }
+        self.checkpoint_count = self.checkpoint_count.wrapping_add(1);
+        let name = format!("diesel_checkpoint_{}", self.checkpoint_count);
+        self.batch_execute(&format!("SAVEPOINT {}", name))?;
+        Ok(Checkpoint { conn: self, name })
+    }
+
+    /// Build a transaction, specifying additional details like the isolation
+    /// level
+    ///
+    /// ```no_run
+    /// # use diesel_oci::OciConnection;
+    /// # use diesel::prelude::*;
+    /// # fn run_test(conn: &mut OciConnection) -> diesel::QueryResult<()> {
+    /// conn.build_transaction().serializable().run(|conn| {
+    ///     diesel::sql_query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///         .execute(conn)?;
+    ///     diesel::sql_query("UPDATE accounts SET balance = balance + 10 WHERE id = 2")
+    ///         .execute(conn)
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_transaction(&mut self) -> OciTransactionBuilder<'_> {
+        OciTransactionBuilder {
+            conn: self,
+            isolation_level: None,
+        }
+    }
+
+    /// Run `f` in a new read only transaction
+    ///
+    /// This is a shorthand for [`transaction_with_isolation_level`](Self::transaction_with_isolation_level)
+    /// with [`IsolationLevel::ReadOnly`]. All queries of `f` see the data as
+    /// committed at the start of the transaction, which gives consistent
+    /// results for reports consisting of several queries:
+    ///
+    /// ```no_run
+    /// # use diesel_oci::OciConnection;
+    /// # use diesel::prelude::*;
+    /// # table! { orders { id -> Integer, amount -> BigInt, } }
+    /// # fn run_test(conn: &mut OciConnection) -> diesel::QueryResult<()> {
+    /// let (orders, large_orders) = conn.transaction_read_only(|conn| {
+    ///     let orders = orders::table.load::<(i32, i64)>(conn)?;
+    ///     let large_orders = orders::table
+    ///         .filter(orders::amount.gt(1000))
+    ///         .count()
+    ///         .get_result::<i64>(conn)?;
+    ///     Ok::<_, diesel::result::Error>((orders, large_orders))
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Statements modifying data fail with `ORA-01456`. The connection
+    /// switches back to autocommit mode once the transaction is finished.
+    pub fn transaction_read_only<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        self.transaction_with_isolation_level(IsolationLevel::ReadOnly, f)
+    }
+
+    /// Call `f` again as long as it fails with a serialization failure (`ORA-08177`)
+    ///
+    /// `f` is called at most `max_retries + 1` times. Any other error, or the
+    /// serialization failure of the last attempt, is returned as is.
+    /// `f` is expected to start its own transaction, otherwise retrying
+    /// is pointless.
+    pub fn retry_on_serialization_failure<T, F>(
+        &mut self,
+        max_retries: u32,
+        mut f: F,
+    ) -> QueryResult<T>
+    where
+        F: FnMut(&mut Self) -> QueryResult<T>,
+    {
+        let mut retries = 0;
+        loop {
+            match f(self) {
+                Err(Error::DatabaseError(DatabaseErrorKind::SerializationFailure, _))
+                    if retries < max_retries =>
+                {
+                    retries += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Run `f` in a transaction with the given isolation level and retry
+    /// the whole transaction on serialization failures
+    ///
+    /// This combines [`transaction_with_isolation_level`](Self::transaction_with_isolation_level)
+    /// and [`retry_on_serialization_failure`](Self::retry_on_serialization_failure),
+    /// which is what most workloads using [`IsolationLevel::Serializable`] need:
+    ///
+    /// ```no_run
+    /// # use diesel_oci::{IsolationLevel, OciConnection};
+    /// # use diesel::prelude::*;
+    /// # fn run_test(conn: &mut OciConnection) -> diesel::QueryResult<()> {
+    /// conn.transaction_with_isolation(IsolationLevel::Serializable, 3, |conn| {
+    ///     diesel::sql_query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///         .execute(conn)?;
+    ///     diesel::sql_query("UPDATE accounts SET balance = balance + 10 WHERE id = 2")
+    ///         .execute(conn)
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transaction_with_isolation<T, F>(
+        &mut self,
+        level: IsolationLevel,
+        max_retries: u32,
+        mut f: F,
+    ) -> QueryResult<T>
+    where
+        F: FnMut(&mut Self) -> QueryResult<T>,
+    {
+        self.retry_on_serialization_failure(max_retries, |conn| {
+            conn.transaction_with_isolation_level(level, &mut f)
+        })
+    }
+}
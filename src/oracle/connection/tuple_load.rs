@@ -0,0 +1,53 @@
+use super::{ErrorHelper, OciConnection};
+use crate::oracle::Oracle;
+use diesel::query_builder::{AsQuery, QueryFragment, QueryId};
+use diesel::result::{Error, QueryResult};
+
+impl OciConnection {
+    /// Load the result of `query` directly into tuples of `T`
+    ///
+    /// This is a faster alternative to [`RunQueryDsl::load`](diesel::RunQueryDsl::load)
+    /// for hot read paths returning many rows. The values are read via the
+    /// typed getters of the `oracle` crate into `T`, which is any
+    /// [`oracle::RowValue`], usually a tuple of primitive types, so no
+    /// [`OracleValue`](crate::OracleValue) is created per field and diesel's
+    /// deserialization is skipped:
+    ///
+    /// ```no_run
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::OciConnection;
+    /// # table! { users { id -> Integer, name -> Text, } }
+    /// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+    /// let users = conn.load_tuples::<(i32, String), _>(users::table.select((users::id, users::name)))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// As the values are converted by the `oracle` crate, they are not
+    /// checked against the sql type of `query` and connection settings like
+    /// [`set_numeric_overflow_policy`](Self::set_numeric_overflow_policy)
+    /// or [`set_bool_truthiness`](Self::set_bool_truthiness) do not apply.
+    /// Values that do not fit into `T` fail with
+    /// [`Error::DeserializationError`]. Queries not returning rows are
+    /// rejected with [`Error::QueryBuilderError`].
+    pub fn load_tuples<T, Q>(&mut self, query: Q) -> QueryResult<Vec<T>>
+    where
+        T: oracle::RowValue,
+        Q: AsQuery,
+        Q::Query: QueryFragment<Oracle> + QueryId,
+    {
+        self.with_prepared_statement(query.as_query(), |mut stmt, bind_collector, _, _| {
+            if !stmt.is_query() {
+                return Err(Error::QueryBuilderError(
+                    "`load_tuples` requires a query returning rows".into(),
+                ));
+            }
+            bind_collector
+                .bind_to(&mut stmt)
+                .map_err(ErrorHelper::from)?;
+            let rows = stmt.into_result_set::<T>(&[]).map_err(ErrorHelper::from)?;
+            rows.map(|row| row.map_err(|e| Error::DeserializationError(e.into())))
+                .collect()
+        })
+    }
+}
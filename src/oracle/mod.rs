@@ -12,5 +12,16 @@ pub(crate) mod query_dsl;
 pub(crate) mod types;
 
 pub use self::backend::Oracle;
-pub use self::connection::{OciConnection, OracleValue};
-pub use self::types::{OciDataType, OciTypeMetadata};
+#[cfg(feature = "r2d2")]
+pub use self::connection::SessionInit;
+#[cfg(feature = "session-stats")]
+pub use self::connection::SessionStatistics;
+pub use self::connection::{
+    AssociativeArray, Checkpoint, IsolationLevel, MigrationTableIssue, OciConnection,
+    OciDatabaseError, OciRow, OciTransactionBuilder, OracleErrorCode, OracleValue, RowIter,
+    ScriptOutput, ScriptResultSet, DEFAULT_RETURNING_BIND_SIZE,
+};
+pub use self::types::{
+    Blob, BoolTruthiness, Clob, IntervalDayToSecond, IntervalYearToMonth, NumericOverflowPolicy,
+    Object, OciDataType, OciTypeMetadata, Rowid, TimestampWithTimeZone, YearMonthInterval,
+};
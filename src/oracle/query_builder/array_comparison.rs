@@ -0,0 +1,83 @@
+use crate::oracle::backend::{Oracle, OracleArrayComparison};
+
+use diesel::expression::array_comparison::{In, Many, MaybeEmpty, NotIn};
+use diesel::query_builder::{AstPass, QueryFragment};
+use diesel::result::{Error, QueryResult};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{HasSqlType, SingleValue};
+
+/// The maximal number of expressions oracle accepts in an `IN (…)` list
+///
+/// Exceeding this limit results in `ORA-01795`.
+pub const MAX_IN_LIST_SIZE: usize = 1000;
+
+// Same as diesel's ansi implementation for `IN`/`NOT IN`, only the
+// values list below differs.
+impl<T, U> QueryFragment<Oracle, OracleArrayComparison> for In<T, U>
+where
+    T: QueryFragment<Oracle>,
+    U: QueryFragment<Oracle> + MaybeEmpty,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if self.values.is_empty() {
+            out.push_sql("1=0");
+        } else {
+            self.left.walk_ast(out.reborrow())?;
+            out.push_sql(" IN (");
+            self.values.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
+
+impl<T, U> QueryFragment<Oracle, OracleArrayComparison> for NotIn<T, U>
+where
+    T: QueryFragment<Oracle>,
+    U: QueryFragment<Oracle> + MaybeEmpty,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if self.values.is_empty() {
+            out.push_sql("1=1");
+        } else {
+            self.left.walk_ast(out.reborrow())?;
+            out.push_sql(" NOT IN (");
+            self.values.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
+
+impl<ST, I> QueryFragment<Oracle, OracleArrayComparison> for Many<ST, I>
+where
+    Oracle: HasSqlType<ST>,
+    ST: SingleValue,
+    I: ToSql<ST, Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if self.values.len() > MAX_IN_LIST_SIZE {
+            return Err(Error::QueryBuilderError(
+                format!(
+                    "Oracle supports at most {} values in an `IN` list, but got {}. \
+                     Split the list into several chunks or bind the values as an array \
+                     (for example a collection type joined via `TABLE(…)`) instead",
+                    MAX_IN_LIST_SIZE,
+                    self.values.len()
+                )
+                .into(),
+            ));
+        }
+        out.unsafe_to_cache_prepared();
+        let mut first = true;
+        for value in &self.values {
+            if first {
+                first = false;
+            } else {
+                out.push_sql(", ");
+            }
+            out.push_bind_param(value)?;
+        }
+        Ok(())
+    }
+}
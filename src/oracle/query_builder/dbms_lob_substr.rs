@@ -0,0 +1,84 @@
+use super::Oracle;
+
+use diesel::expression::{AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Integer;
+
+/// The oracle `DBMS_LOB.SUBSTR` function
+///
+/// Reads `amount` characters (for a `CLOB`) or bytes (for a `BLOB`) of
+/// `lob`, starting at the 1-based `offset`. Only that part is transferred
+/// to the client, which is useful to show a preview of large documents
+/// without fetching them completely:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::dbms_lob_substr;
+/// # table! { documents { id -> Integer, content -> Text, } }
+/// // the first 1000 characters of each document
+/// let query = documents::table.select(dbms_lob_substr(documents::content, 1000, 1));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT DBMS_LOB.SUBSTR(\"DOCUMENTS\".\"CONTENT\", :in0, :in1) \
+///      FROM \"DOCUMENTS\" -- binds: [1000, 1]"
+/// );
+/// ```
+///
+/// The result is a `VARCHAR2` or `RAW` value, so `amount` is limited to
+/// 4000 bytes (32767 bytes if `MAX_STRING_SIZE` is set to `EXTENDED`).
+/// The result is `NULL` if `offset` is beyond the end of the LOB.
+pub fn dbms_lob_substr<T>(lob: T, amount: i32, offset: i32) -> DbmsLobSubstr<T>
+where
+    T: Expression,
+{
+    DbmsLobSubstr {
+        lob,
+        amount,
+        offset,
+    }
+}
+
+/// The expression returned by [`dbms_lob_substr()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct DbmsLobSubstr<T> {
+    lob: T,
+    amount: i32,
+    offset: i32,
+}
+
+impl<T> Expression for DbmsLobSubstr<T>
+where
+    T: Expression,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<T, QS> AppearsOnTable<QS> for DbmsLobSubstr<T> where T: AppearsOnTable<QS> {}
+
+impl<T, QS> SelectableExpression<QS> for DbmsLobSubstr<T> where T: SelectableExpression<QS> {}
+
+impl<T, GB> ValidGrouping<GB> for DbmsLobSubstr<T>
+where
+    T: ValidGrouping<GB>,
+{
+    type IsAggregate = T::IsAggregate;
+}
+
+impl<T> QueryFragment<Oracle> for DbmsLobSubstr<T>
+where
+    T: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("DBMS_LOB.SUBSTR(");
+        self.lob.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        out.push_bind_param::<Integer, _>(&self.amount)?;
+        out.push_sql(", ");
+        out.push_bind_param::<Integer, _>(&self.offset)?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -4,6 +4,12 @@ use diesel::expression::exists::Exists;
 use diesel::query_builder::{AstPass, QueryFragment};
 use diesel::result::QueryResult;
 
+// Oracle has no boolean type in sql, so the `EXISTS` predicate is wrapped
+// into a `CASE` expression to be usable as select clause expression.
+//
+// The subselect is rendered as is: a subselect with a from clause emits
+// that one, a subselect without a from clause emits `FROM DUAL` via
+// `NoFromClause`, so both variants never end up with more than one from clause.
 impl<T> QueryFragment<Oracle, OracleExistsSyntax> for Exists<T>
 where
     T: QueryFragment<Oracle>,
@@ -0,0 +1,118 @@
+use crate::oracle::Oracle;
+
+use diesel::expression::expression_types::NotSelectable;
+use diesel::expression::is_contained_in_group_by::No;
+use diesel::expression::{AppearsOnTable, Expression, IsContainedInGroupBy};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+
+/// Creates a `GROUPING SETS (…)` expression to be used with `.group_by()`
+///
+/// `sets` is a tuple of grouping sets. Each set is either a single
+/// expression, a tuple of expressions or [`grand_total()`] for the empty
+/// grouping set `()`. Each set is rendered in its own parentheses:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel::dsl::count_star;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::{grand_total, grouping_sets};
+/// # table! { users { id -> Integer, name -> Text, city -> Text, } }
+/// let query = users::table
+///     .group_by(grouping_sets(((users::name, users::city), users::city, grand_total())))
+///     .select((users::city, count_star()));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT \"USERS\".\"CITY\", COUNT(*) FROM \"USERS\" GROUP BY GROUPING SETS \
+///      ((\"USERS\".\"NAME\", \"USERS\".\"CITY\"), (\"USERS\".\"CITY\"), ()) -- binds: []"
+/// );
+/// ```
+///
+/// Columns that are not part of the grouping set of a row are `NULL`,
+/// so they should be loaded as `Option<T>`.
+pub fn grouping_sets<T>(sets: T) -> GroupingSets<T>
+where
+    GroupingSets<T>: Expression,
+{
+    GroupingSets(sets)
+}
+
+/// The empty grouping set `()`, see [`grouping_sets`]
+///
+/// This produces a single row aggregating all rows of the query.
+pub fn grand_total() -> GrandTotal {
+    GrandTotal
+}
+
+/// The return type of [`grouping_sets`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct GroupingSets<T>(T);
+
+/// The return type of [`grand_total`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct GrandTotal;
+
+impl Expression for GrandTotal {
+    type SqlType = NotSelectable;
+}
+
+impl<QS> AppearsOnTable<QS> for GrandTotal {}
+
+impl<Col> IsContainedInGroupBy<Col> for GrandTotal {
+    type Output = No;
+}
+
+impl QueryFragment<Oracle> for GrandTotal {
+    fn walk_ast<'b>(&'b self, _out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+// a column is part of the group by clause if it is part of any grouping set
+impl<T, Col> IsContainedInGroupBy<Col> for GroupingSets<T>
+where
+    T: IsContainedInGroupBy<Col>,
+{
+    type Output = T::Output;
+}
+
+macro_rules! grouping_sets_impls {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T: Expression,)+> Expression for GroupingSets<($($T,)+)> {
+                type SqlType = NotSelectable;
+            }
+
+            impl<$($T,)+ QS> AppearsOnTable<QS> for GroupingSets<($($T,)+)>
+            where
+                $($T: AppearsOnTable<QS>,)+
+            {
+            }
+
+            impl<$($T: QueryFragment<Oracle>,)+> QueryFragment<Oracle> for GroupingSets<($($T,)+)> {
+                #[allow(unused_assignments)]
+                fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+                    out.push_sql("GROUPING SETS (");
+                    let mut needs_comma = false;
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        out.push_sql("(");
+                        self.0.$idx.walk_ast(out.reborrow())?;
+                        out.push_sql(")");
+                        needs_comma = true;
+                    )+
+                    out.push_sql(")");
+                    Ok(())
+                }
+            }
+        )+
+    }
+}
+
+diesel_derives::__diesel_for_each_tuple!(grouping_sets_impls);
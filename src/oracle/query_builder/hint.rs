@@ -0,0 +1,120 @@
+use super::{OciQueryBuilder, Oracle};
+
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::{Error, QueryResult};
+
+/// Adds oracle optimizer hints to a query
+///
+/// Hints are emitted as `/*+ … */` comment directly after the `SELECT`
+/// keyword of the query. Multiple hints are combined into the same comment,
+/// as oracle only considers the first hint comment of a statement.
+///
+/// Hints are only emitted for the outermost query, they are silently
+/// ignored if the hinted query is used as subquery.
+pub trait HintDsl: Query + Sized {
+    /// Request a parallel execution of the query with the given degree of
+    /// parallelism via `/*+ PARALLEL(degree) */`
+    ///
+    /// This is only a hint for the optimizer: parallel execution needs to be
+    /// enabled and configured on the server (`PARALLEL_MAX_SERVERS`,
+    /// resource manager plans, …), otherwise the query runs serially.
+    fn parallel(self, degree: u32) -> Hinted<Self> {
+        Hinted {
+            query: self,
+            hint: format!("PARALLEL({})", degree),
+        }
+    }
+
+    /// Add an arbitrary optimizer hint
+    ///
+    /// The text is emitted as is, so any hint oracle understands can be
+    /// used, including those suppressing an access path:
+    ///
+    /// ```
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::Oracle;
+    /// # use diesel_oci::query_builder::HintDsl;
+    /// # table! { users { id -> Integer, name -> Text, } }
+    /// let query = users::table
+    ///     .select(users::id)
+    ///     .hint("NO_INDEX(users users_name_idx)")
+    ///     .hint("FULL(users)");
+    /// assert_eq!(
+    ///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+    ///     "SELECT /*+ FULL(users) NO_INDEX(users users_name_idx) */ \"USERS\".\"ID\" \
+    ///      FROM \"USERS\" -- binds: []"
+    /// );
+    /// ```
+    ///
+    /// Table names in hints refer to the name or alias of the table as used
+    /// in the query. Oracle silently ignores hints it does not understand.
+    /// Building the query fails if the text contains `*/`, as that would
+    /// end the hint comment.
+    fn hint(self, hint: &str) -> Hinted<Self> {
+        Hinted {
+            query: self,
+            hint: hint.to_owned(),
+        }
+    }
+}
+
+impl<T> HintDsl for T where T: Query {}
+
+/// A query with an optimizer hint, see [`HintDsl`]
+#[derive(Debug, Clone)]
+pub struct Hinted<Q> {
+    query: Q,
+    hint: String,
+}
+
+impl<Q> QueryId for Hinted<Q> {
+    type QueryId = ();
+
+    // the hint is part of the sql, but not part of the type
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q> Query for Hinted<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Q> QueryFragment<Oracle> for Hinted<Q>
+where
+    Q: QueryFragment<Oracle>,
+{
+    fn to_sql(&self, out: &mut OciQueryBuilder, backend: &Oracle) -> QueryResult<()> {
+        if self.hint.contains("*/") {
+            return Err(Error::QueryBuilderError(
+                format!("Invalid optimizer hint `{}`", self.hint).into(),
+            ));
+        }
+        let start = out.sql.len();
+        self.query.to_sql(out, backend)?;
+
+        let select_end = start + "SELECT".len();
+        if !out.sql[start..].starts_with("SELECT") {
+            return Err(Error::QueryBuilderError(
+                "Optimizer hints are only supported for select statements".into(),
+            ));
+        }
+        if out.sql[select_end..].starts_with(" /*+ ") {
+            // merge with the hints of an inner `Hinted` query
+            out.sql
+                .insert_str(select_end + " /*+ ".len(), &format!("{} ", self.hint));
+        } else {
+            out.sql
+                .insert_str(select_end, &format!(" /*+ {} */", self.hint));
+        }
+        Ok(())
+    }
+
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        self.query.walk_ast(out)
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for Hinted<Q> {}
@@ -0,0 +1,252 @@
+use super::Oracle;
+
+use diesel::expression::{AppearsOnTable, Expression, SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, FromClause, QueryFragment, QueryId, SelectStatement};
+use diesel::query_source::{AppearsInFromClause, QuerySource};
+use diesel::result::QueryResult;
+use diesel::Table;
+
+/// Join each row of `left` with the rows returned by `right`
+/// via `CROSS APPLY`
+///
+/// Rows of `left` for which `right` returns no rows are omitted.
+/// See [`lateral()`] for details.
+pub fn cross_apply<L, R>(
+    left: L,
+    right: R,
+    alias: &'static str,
+) -> SelectStatement<FromClause<LateralJoin<L, R>>>
+where
+    L: Table,
+    L::FromClause: Clone,
+    R: Clone,
+{
+    SelectStatement::simple(LateralJoin::new(
+        left,
+        right,
+        alias,
+        LateralKind::CrossApply,
+    ))
+}
+
+/// Join each row of `left` with the rows returned by `right`
+/// via `OUTER APPLY`
+///
+/// Rows of `left` for which `right` returns no rows are kept, with all
+/// columns of `right` set to `NULL`. See [`lateral()`] for details.
+pub fn outer_apply<L, R>(
+    left: L,
+    right: R,
+    alias: &'static str,
+) -> SelectStatement<FromClause<LateralJoin<L, R>>>
+where
+    L: Table,
+    L::FromClause: Clone,
+    R: Clone,
+{
+    SelectStatement::simple(LateralJoin::new(
+        left,
+        right,
+        alias,
+        LateralKind::OuterApply,
+    ))
+}
+
+/// Join each row of `left` with the rows returned by `right`
+/// via a `LATERAL` inline view
+///
+/// This generates `FROM left, LATERAL (right) alias`. In contrast to
+/// a normal subquery `right` may refer to the columns of `left`, for example
+/// to select the top n rows per row of `left`.
+///
+/// Diesel cannot express such a correlated subquery in a type safe way, so
+/// the references to `left` in `right` and any columns of `right` in the
+/// select clause need to be written via [`sql()`](diesel::dsl::sql).
+/// The columns of `left` can be used as usual in the where and order clause,
+/// the default select clause contains all columns of `left`.
+///
+/// ```no_run
+/// # use diesel::prelude::*;
+/// # use diesel::dsl::sql;
+/// # use diesel::sql_types::{Bool, Integer, Text};
+/// # use diesel_oci::OciConnection;
+/// # use diesel_oci::query_builder::lateral;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// # table! { posts { id -> Integer, user_id -> Integer, title -> Text, } }
+/// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+/// // the latest 3 posts of each user
+/// let latest_posts = posts::table
+///     .select(posts::title)
+///     .filter(sql::<Bool>("\"POSTS\".\"USER_ID\" = \"USERS\".\"ID\""))
+///     .order(posts::id.desc())
+///     .limit(3);
+/// let res = lateral(users::table, latest_posts, "latest")
+///     .select(sql::<(Integer, Text)>("\"USERS\".\"ID\", \"LATEST\".\"TITLE\""))
+///     .load::<(i32, String)>(conn)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn lateral<L, R>(
+    left: L,
+    right: R,
+    alias: &'static str,
+) -> SelectStatement<FromClause<LateralJoin<L, R>>>
+where
+    L: Table,
+    L::FromClause: Clone,
+    R: Clone,
+{
+    SelectStatement::simple(LateralJoin::new(left, right, alias, LateralKind::Lateral))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LateralKind {
+    CrossApply,
+    OuterApply,
+    Lateral,
+}
+
+/// The query source created by [`lateral()`], [`cross_apply()`] and [`outer_apply()`]
+pub struct LateralJoin<L: QuerySource, R> {
+    left: L::FromClause,
+    right: R,
+    alias: &'static str,
+    kind: LateralKind,
+}
+
+impl<L, R> LateralJoin<L, R>
+where
+    L: QuerySource,
+{
+    fn new(left: L, right: R, alias: &'static str, kind: LateralKind) -> Self {
+        Self {
+            left: left.from_clause(),
+            right,
+            alias,
+            kind,
+        }
+    }
+}
+
+impl<L, R> Clone for LateralJoin<L, R>
+where
+    L: QuerySource,
+    L::FromClause: Clone,
+    R: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            alias: self.alias,
+            kind: self.kind,
+        }
+    }
+}
+
+impl<L, R> QueryId for LateralJoin<L, R>
+where
+    L: QuerySource,
+{
+    type QueryId = ();
+
+    // the kind of join and the alias are part of the sql, but not part of the type
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<L, R> QuerySource for LateralJoin<L, R>
+where
+    L: Table,
+    L::FromClause: Clone,
+    R: Clone,
+{
+    type FromClause = Self;
+    type DefaultSelection = LeftColumns<L>;
+
+    fn from_clause(&self) -> Self::FromClause {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        LeftColumns(L::all_columns())
+    }
+}
+
+impl<L, R, T> AppearsInFromClause<T> for LateralJoin<L, R>
+where
+    L: QuerySource + AppearsInFromClause<T>,
+{
+    type Count = L::Count;
+}
+
+impl<L, R> QueryFragment<Oracle> for LateralJoin<L, R>
+where
+    L: Table,
+    L::FromClause: QueryFragment<Oracle>,
+    R: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        match self.kind {
+            LateralKind::CrossApply => out.push_sql(" CROSS APPLY ("),
+            LateralKind::OuterApply => out.push_sql(" OUTER APPLY ("),
+            LateralKind::Lateral => out.push_sql(", LATERAL ("),
+        }
+        self.right.walk_ast(out.reborrow())?;
+        out.push_sql(") ");
+        out.push_identifier(self.alias)?;
+        Ok(())
+    }
+}
+
+/// The default selection of a [`LateralJoin`], all columns of the left table
+#[derive(Debug, Clone, Copy)]
+pub struct LeftColumns<L: Table>(L::AllColumns);
+
+impl<L> Expression for LeftColumns<L>
+where
+    L: Table,
+{
+    type SqlType = <L::AllColumns as Expression>::SqlType;
+}
+
+impl<L, R> AppearsOnTable<LateralJoin<L, R>> for LeftColumns<L>
+where
+    L: Table,
+    LateralJoin<L, R>: AppearsInFromClause<L>,
+{
+}
+
+impl<L, R> SelectableExpression<LateralJoin<L, R>> for LeftColumns<L>
+where
+    L: Table,
+    LateralJoin<L, R>: AppearsInFromClause<L>,
+{
+}
+
+impl<L, GB> ValidGrouping<GB> for LeftColumns<L>
+where
+    L: Table,
+    L::AllColumns: ValidGrouping<GB>,
+{
+    type IsAggregate = <L::AllColumns as ValidGrouping<GB>>::IsAggregate;
+}
+
+impl<L> QueryFragment<Oracle> for LeftColumns<L>
+where
+    L: Table,
+    L::AllColumns: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+impl<L> QueryId for LeftColumns<L>
+where
+    L: Table,
+{
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
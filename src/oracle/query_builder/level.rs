@@ -0,0 +1,39 @@
+use super::Oracle;
+
+use diesel::expression::{is_aggregate, AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::BigInt;
+
+/// The oracle `LEVEL` pseudo column
+///
+/// In a hierarchical query (`CONNECT BY`) this is the depth of the
+/// current row, starting with 1 for the root rows. It is also commonly
+/// used to generate rows, as in `SELECT LEVEL FROM DUAL CONNECT BY LEVEL <= 10`.
+pub fn level() -> Level {
+    Level
+}
+
+/// The expression returned by [`level()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Level;
+
+impl Expression for Level {
+    type SqlType = BigInt;
+}
+
+impl<QS> AppearsOnTable<QS> for Level {}
+
+impl<QS> SelectableExpression<QS> for Level {}
+
+impl<GB> ValidGrouping<GB> for Level {
+    type IsAggregate = is_aggregate::Never;
+}
+
+impl QueryFragment<Oracle> for Level {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("LEVEL");
+        Ok(())
+    }
+}
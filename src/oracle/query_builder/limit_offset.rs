@@ -1,13 +1,312 @@
+use super::{split_list, OciQueryBuilder};
 use crate::oracle::Oracle;
 use diesel::query_builder::{AstPass, QueryFragment};
 use diesel::query_builder::{BoxedLimitOffsetClause, IntoBoxedClause, LimitOffsetClause};
 use diesel::query_builder::{LimitClause, NoLimitClause};
 use diesel::query_builder::{NoOffsetClause, OffsetClause};
 use diesel::result::QueryResult;
+use diesel::sql_types::BigInt;
+
+// Oracle 12c and newer support `OFFSET … ROWS FETCH NEXT … ROWS ONLY`,
+// older versions need the query to be wrapped in a subquery filtering on
+// `ROWNUM`. Which syntax is used is a setting of the connection, that is
+// only known to the query builder. So the limit and offset clauses only
+// push markers around their binds, which the query builder replaces with
+// the sql of the configured syntax. The binds are the same for both, so
+// collecting them does not depend on the setting.
+//
+// The legacy syntax looks like this, depending on the clauses present:
+//
+// SELECT * FROM (…) WHERE ROWNUM <= :limit
+// SELECT <projection> FROM (SELECT "diesel_limit_offset".*, ROWNUM "diesel_rn"
+//     FROM (…) "diesel_limit_offset") WHERE "diesel_rn" > :offset
+// SELECT <projection> FROM (SELECT "diesel_limit_offset".*, ROWNUM "diesel_rn"
+//     FROM (…) "diesel_limit_offset") WHERE "diesel_rn" - :offset BETWEEN 1 AND :limit
+//
+// The row number must not be part of the result, otherwise the query
+// cannot be used as subquery, like for `.eq_any()`. So each column of the
+// wrapped query gets a positional alias, and the projection selects these
+// columns with their original names:
+//
+// SELECT "diesel_c0" "ID" FROM (… (SELECT "T"."ID" "diesel_c0" FROM "T") …)
+//
+// If the columns cannot be determined, like for `SELECT *`, the projection
+// is `*` and the row number is returned as additional last column.
+//
+// Features that only exist for the 12c syntax push a marker as well, which
+// fails the query with the legacy syntax.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum LimitOffsetPart {
+    /// Pushed at the end of each select statement, after its `ORDER BY` clause
+    Start {
+        limit: bool,
+        offset: bool,
+    },
+    AfterOffset,
+    AfterLimit,
+    /// Pushed by `.with_ties()`
+    WithTies,
+    /// Pushed by `.limit_percent()`
+    LimitPercent,
+}
+
+const NONE_MARKER: &str = "\u{0}limit_offset:none\u{0}";
+const LIMIT_MARKER: &str = "\u{0}limit_offset:limit\u{0}";
+const OFFSET_MARKER: &str = "\u{0}limit_offset:offset\u{0}";
+const LIMIT_OFFSET_MARKER: &str = "\u{0}limit_offset:limit_offset\u{0}";
+const AFTER_OFFSET_MARKER: &str = "\u{0}limit_offset:after_offset\u{0}";
+const AFTER_LIMIT_MARKER: &str = "\u{0}limit_offset:after_limit\u{0}";
+const WITH_TIES_MARKER: &str = "\u{0}limit_offset:with_ties\u{0}";
+const LIMIT_PERCENT_MARKER: &str = "\u{0}limit_offset:limit_percent\u{0}";
+
+const LEGACY_ROW_NUMBER_PREFIX: &str =
+    " FROM (SELECT \"diesel_limit_offset\".*, ROWNUM \"diesel_rn\" FROM (";
+const LEGACY_ROW_NUMBER_SUFFIX: &str = ") \"diesel_limit_offset\") WHERE \"diesel_rn\" ";
+
+impl LimitOffsetPart {
+    fn marker(self) -> &'static str {
+        match self {
+            LimitOffsetPart::Start {
+                limit: false,
+                offset: false,
+            } => NONE_MARKER,
+            LimitOffsetPart::Start {
+                limit: true,
+                offset: false,
+            } => LIMIT_MARKER,
+            LimitOffsetPart::Start {
+                limit: false,
+                offset: true,
+            } => OFFSET_MARKER,
+            LimitOffsetPart::Start {
+                limit: true,
+                offset: true,
+            } => LIMIT_OFFSET_MARKER,
+            LimitOffsetPart::AfterOffset => AFTER_OFFSET_MARKER,
+            LimitOffsetPart::AfterLimit => AFTER_LIMIT_MARKER,
+            LimitOffsetPart::WithTies => WITH_TIES_MARKER,
+            LimitOffsetPart::LimitPercent => LIMIT_PERCENT_MARKER,
+        }
+    }
+
+    pub(super) fn from_marker(sql: &str) -> Option<Self> {
+        let part = match sql {
+            NONE_MARKER => LimitOffsetPart::Start {
+                limit: false,
+                offset: false,
+            },
+            LIMIT_MARKER => LimitOffsetPart::Start {
+                limit: true,
+                offset: false,
+            },
+            OFFSET_MARKER => LimitOffsetPart::Start {
+                limit: false,
+                offset: true,
+            },
+            LIMIT_OFFSET_MARKER => LimitOffsetPart::Start {
+                limit: true,
+                offset: true,
+            },
+            AFTER_OFFSET_MARKER => LimitOffsetPart::AfterOffset,
+            AFTER_LIMIT_MARKER => LimitOffsetPart::AfterLimit,
+            WITH_TIES_MARKER => LimitOffsetPart::WithTies,
+            LIMIT_PERCENT_MARKER => LimitOffsetPart::LimitPercent,
+            _ => return None,
+        };
+        Some(part)
+    }
+}
+
+impl OciQueryBuilder {
+    pub(super) fn push_limit_offset(&mut self, part: LimitOffsetPart) {
+        match part {
+            LimitOffsetPart::Start { limit, offset } => {
+                let select_start = self.select_starts.pop().unwrap_or(0);
+                self.limit_offset = (limit, offset);
+                match (self.legacy_limit_offset, limit, offset) {
+                    (_, false, false) => {}
+                    (false, true, false) => self.sql.push_str(" FETCH FIRST "),
+                    (false, _, true) => self.sql.push_str(" OFFSET "),
+                    (true, true, false) => {
+                        self.sql.insert_str(select_start, "SELECT * FROM (");
+                        self.sql.push_str(") WHERE ROWNUM <= ");
+                    }
+                    (true, _, true) => {
+                        let projection = alias_columns(&mut self.sql, select_start);
+                        let prefix = format!(
+                            "SELECT {}{}",
+                            projection.as_deref().unwrap_or("*"),
+                            LEGACY_ROW_NUMBER_PREFIX
+                        );
+                        self.sql.insert_str(select_start, &prefix);
+                        self.sql.push_str(LEGACY_ROW_NUMBER_SUFFIX);
+                        self.sql.push_str(if limit { "- " } else { "> " });
+                    }
+                }
+            }
+            LimitOffsetPart::AfterOffset => {
+                let (limit, _) = self.limit_offset;
+                let sql = match (self.legacy_limit_offset, limit) {
+                    (false, true) => " ROWS FETCH NEXT ",
+                    (false, false) => " ROWS ",
+                    (true, true) => " BETWEEN 1 AND ",
+                    (true, false) => "",
+                };
+                self.sql.push_str(sql);
+            }
+            LimitOffsetPart::AfterLimit => {
+                if !self.legacy_limit_offset {
+                    self.sql.push_str(" ROWS ONLY ");
+                }
+            }
+            LimitOffsetPart::WithTies if self.legacy_limit_offset => self.set_error(
+                "`.with_ties()` requires oracle 12c, \
+                 it cannot be used with `set_legacy_limit_offset`"
+                    .to_owned(),
+            ),
+            LimitOffsetPart::LimitPercent if self.legacy_limit_offset => self.set_error(
+                "`.limit_percent()` requires oracle 12c, \
+                 it cannot be used with `set_legacy_limit_offset`"
+                    .to_owned(),
+            ),
+            LimitOffsetPart::WithTies | LimitOffsetPart::LimitPercent => {}
+        }
+    }
+}
+
+// Gives each column of the select statement at `start` a positional alias
+// and returns the projection of these columns with their original names.
+// Returns `None` without changing the statement if a column cannot be
+// handled, like `*` or an expression ending with a keyword.
+fn alias_columns(sql: &mut String, start: usize) -> Option<String> {
+    let statement = &sql[start..];
+    let list = statement.strip_prefix("SELECT ")?;
+    let list = list.strip_prefix("DISTINCT ").unwrap_or(list);
+    let list_start = start + statement.len() - list.len();
+    let list_end = list_start + find_top_level(list, " FROM ")?;
+
+    let mut columns = Vec::new();
+    let mut projection = Vec::new();
+    for (idx, item) in split_list(&sql[list_start..list_end]).iter().enumerate() {
+        let (expression, name) = select_item(item)?;
+        let alias = format!("\"diesel_c{}\"", idx);
+        columns.push(format!("{} {}", expression, alias));
+        projection.push(match name {
+            Some(name) => format!("{} {}", alias, name),
+            None => alias,
+        });
+    }
+    sql.replace_range(list_start..list_end, &columns.join(", "));
+    Some(projection.join(", "))
+}
+
+// Splits an item of a select list into the expression without its alias
+// and the name of the column, if the name is a quoted identifier
+fn select_item(item: &str) -> Option<(&str, Option<&str>)> {
+    match item.chars().last()? {
+        '"' => {
+            let name_start = item[..item.len() - 1].rfind('"')?;
+            let (expression, name) = item.split_at(name_start);
+            let before = expression.trim_end();
+            if before.is_empty() || expression.ends_with('.') {
+                // a column like `"T"."ID"`
+                Some((item, Some(name)))
+            } else if before.ends_with(|c: char| c.is_alphanumeric() || "_$#)\"'".contains(c))
+                && before.len() < expression.len()
+            {
+                // an expression with an alias, like `COUNT(*) AS "N"`
+                let expression = match before.len().checked_sub(3) {
+                    Some(idx) if before[idx..].eq_ignore_ascii_case(" AS") => &before[..idx],
+                    _ => before,
+                };
+                Some((expression, Some(name)))
+            } else {
+                // an expression ending with a column, like `"T"."A" + "T"."B"`
+                Some((item, None))
+            }
+        }
+        ')' | '\'' | '0'..='9' => Some((item, None)),
+        _ => None,
+    }
+}
+
+// The position of `pattern` in `sql` outside of parentheses, quoted
+// identifiers and string literals
+fn find_top_level(sql: &str, pattern: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut quote = None;
+    for (idx, c) in sql.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 && sql[idx..].starts_with(pattern) => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn walk_limit_offset<'b>(
+    limit: Option<&'b dyn QueryFragment<Oracle>>,
+    offset: Option<&'b dyn QueryFragment<Oracle>>,
+    mut out: AstPass<'_, 'b, Oracle>,
+) -> QueryResult<()> {
+    out.push_sql(
+        LimitOffsetPart::Start {
+            limit: limit.is_some(),
+            offset: offset.is_some(),
+        }
+        .marker(),
+    );
+    if let Some(offset) = offset {
+        offset.walk_ast(out.reborrow())?;
+        out.push_sql(LimitOffsetPart::AfterOffset.marker());
+    }
+    if let Some(limit) = limit {
+        limit.walk_ast(out.reborrow())?;
+        out.push_sql(LimitOffsetPart::AfterLimit.marker());
+    }
+    Ok(())
+}
+
+/// Marks the end of a select statement that renders its limit clause
+/// itself, like `.with_ties()`
+pub(super) fn walk_without_limit_offset(out: AstPass<'_, '_, Oracle>) -> QueryResult<()> {
+    walk_limit_offset(None, None, out)
+}
+
+/// Marks a limit clause that is only supported with the 12c syntax
+pub(super) fn walk_requires_fetch(part: LimitOffsetPart, mut out: AstPass<'_, '_, Oracle>) {
+    out.push_sql(part.marker());
+}
+
+/// Pushes the offset and the limit of a query that is not a select
+/// statement, like `paginate()`
+pub(super) fn walk_offset_limit<'b>(
+    offset: &'b i64,
+    limit: &'b i64,
+    mut out: AstPass<'_, 'b, Oracle>,
+) -> QueryResult<()> {
+    out.push_sql(
+        LimitOffsetPart::Start {
+            limit: true,
+            offset: true,
+        }
+        .marker(),
+    );
+    out.push_bind_param::<BigInt, _>(offset)?;
+    out.push_sql(LimitOffsetPart::AfterOffset.marker());
+    out.push_bind_param::<BigInt, _>(limit)?;
+    out.push_sql(LimitOffsetPart::AfterLimit.marker());
+    Ok(())
+}
 
 impl QueryFragment<Oracle> for LimitOffsetClause<NoLimitClause, NoOffsetClause> {
-    fn walk_ast(&self, _out: AstPass<Oracle>) -> QueryResult<()> {
-        Ok(())
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        walk_limit_offset(None, None, out)
     }
 }
 
@@ -15,11 +314,8 @@ impl<L> QueryFragment<Oracle> for LimitOffsetClause<LimitClause<L>, NoOffsetClau
 where
     L: QueryFragment<Oracle>,
 {
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
-        out.push_sql(" FETCH FIRST ");
-        self.limit_clause.0.walk_ast(out.reborrow())?;
-        out.push_sql(" ROWS ONLY ");
-        Ok(())
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        walk_limit_offset(Some(&self.limit_clause.0), None, out)
     }
 }
 
@@ -27,11 +323,8 @@ impl<O> QueryFragment<Oracle> for LimitOffsetClause<NoLimitClause, OffsetClause<
 where
     O: QueryFragment<Oracle>,
 {
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
-        out.push_sql(" OFFSET ");
-        self.offset_clause.0.walk_ast(out.reborrow())?;
-        out.push_sql(" ROWS ");
-        Ok(())
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        walk_limit_offset(None, Some(&self.offset_clause.0), out)
     }
 }
 
@@ -40,39 +333,23 @@ where
     L: QueryFragment<Oracle>,
     O: QueryFragment<Oracle>,
 {
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
-        out.push_sql(" OFFSET ");
-        self.offset_clause.0.walk_ast(out.reborrow())?;
-        out.push_sql(" ROWS FETCH NEXT ");
-        self.limit_clause.0.walk_ast(out.reborrow())?;
-        out.push_sql(" ROWS ONLY ");
-        Ok(())
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        walk_limit_offset(Some(&self.limit_clause.0), Some(&self.offset_clause.0), out)
     }
 }
 
 impl<'a> QueryFragment<Oracle> for BoxedLimitOffsetClause<'a, Oracle> {
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
-        match (self.limit.as_ref(), self.offset.as_ref()) {
-            (Some(limit), Some(offset)) => {
-                out.push_sql(" OFFSET ");
-                offset.walk_ast(out.reborrow())?;
-                out.push_sql(" ROWS FETCH NEXT ");
-                limit.walk_ast(out.reborrow())?;
-                out.push_sql(" ROWS ONLY ");
-            }
-            (Some(limit), None) => {
-                out.push_sql(" FETCH FIRST ");
-                limit.walk_ast(out.reborrow())?;
-                out.push_sql(" ROWS ONLY ");
-            }
-            (None, Some(offset)) => {
-                out.push_sql(" OFFSET ");
-                offset.walk_ast(out.reborrow())?;
-                out.push_sql(" ROWS ");
-            }
-            (None, None) => {}
-        }
-        Ok(())
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        // drop the `Send` bound of the boxed clauses
+        let limit = self
+            .limit
+            .as_deref()
+            .map(|l| l as &dyn QueryFragment<Oracle>);
+        let offset = self
+            .offset
+            .as_deref()
+            .map(|o| o as &dyn QueryFragment<Oracle>);
+        walk_limit_offset(limit, offset, out)
     }
 }
 
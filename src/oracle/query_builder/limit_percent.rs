@@ -0,0 +1,87 @@
+use super::limit_offset::LimitOffsetPart;
+use super::{is_empty, Oracle};
+
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId, SelectStatement};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::{Error, QueryResult};
+use diesel::sql_types::Double;
+
+/// Limits a query to a percentage of its rows via
+/// `FETCH FIRST … PERCENT ROWS ONLY`
+pub trait LimitPercentDsl: Sized {
+    /// Only return the first `percent` percent of the rows of the query
+    ///
+    /// The number of returned rows is rounded up, so a non empty result
+    /// always contains at least one row for a positive percentage. Values
+    /// above 100 return all rows. Use `.order()` to control which rows are
+    /// considered to be the first ones.
+    ///
+    /// ```
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::Oracle;
+    /// # use diesel_oci::query_builder::LimitPercentDsl;
+    /// # table! { users { id -> Integer, name -> Text, } }
+    /// let query = users::table
+    ///     .select(users::name)
+    ///     .order(users::id)
+    ///     .limit_percent(10.0);
+    /// assert_eq!(
+    ///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+    ///     "SELECT \"USERS\".\"NAME\" FROM \"USERS\" ORDER BY \"USERS\".\"ID\" \
+    ///      FETCH FIRST :in0 PERCENT ROWS ONLY -- binds: [10.0]"
+    /// );
+    /// ```
+    ///
+    /// The query must not contain a `LIMIT`/`OFFSET` clause, executing such
+    /// a query returns an error. This requires oracle 12c, so it fails with
+    /// [`OciConnection::set_legacy_limit_offset`](crate::OciConnection::set_legacy_limit_offset)
+    /// as well.
+    fn limit_percent(self, percent: f64) -> LimitPercent<Self> {
+        LimitPercent {
+            query: self,
+            percent,
+        }
+    }
+}
+
+impl<F, S, D, W, O, LOf, G, H, LC> LimitPercentDsl
+    for SelectStatement<F, S, D, W, O, LOf, G, H, LC>
+{
+}
+
+/// A query limited to a percentage of its rows, see [`LimitPercentDsl`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct LimitPercent<Q> {
+    query: Q,
+    percent: f64,
+}
+
+impl<Q> Query for LimitPercent<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<F, S, D, W, O, LOf, G, H, LC> QueryFragment<Oracle>
+    for LimitPercent<SelectStatement<F, S, D, W, O, LOf, G, H, LC>>
+where
+    SelectStatement<F, S, D, W, O, LOf, G, H, LC>: QueryFragment<Oracle>,
+    LOf: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if !is_empty(&self.query.limit_offset)? {
+            return Err(Error::QueryBuilderError(
+                "`.limit_percent()` cannot be combined with `.limit()` or `.offset()`".into(),
+            ));
+        }
+        self.query.walk_ast(out.reborrow())?;
+        super::limit_offset::walk_requires_fetch(LimitOffsetPart::LimitPercent, out.reborrow());
+        out.push_sql(" FETCH FIRST ");
+        out.push_bind_param::<Double, _>(&self.percent)?;
+        out.push_sql(" PERCENT ROWS ONLY");
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for LimitPercent<Q> {}
@@ -0,0 +1,158 @@
+use super::Oracle;
+use crate::oracle::connection::OciConnection;
+
+use diesel::connection::{Connection, TransactionManager};
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::query_dsl::{LoadQuery, RunQueryDsl};
+use diesel::result::{Error, QueryResult};
+
+/// Row locking for select statements via `SELECT … FOR UPDATE`
+///
+/// Diesel's own `for_update` is not available for the oracle backend,
+/// use [`lock_for_update`](OciLockingDsl::lock_for_update) instead.
+///
+/// Oracle acquires the locks for *all* selected rows while executing the
+/// statement, before the first row is fetched. The locks therefore do not
+/// depend on how many rows are actually read from the result and are held
+/// until the end of the current transaction. As the connection runs in
+/// autocommit mode outside of transactions, a locking query should always
+/// be executed inside of [`Connection::transaction`](diesel::Connection::transaction),
+/// otherwise the locks are released again immediately.
+/// [`LockedQuery::load_locked`] enforces this.
+///
+/// Oracle does not allow to combine `FOR UPDATE` with `DISTINCT`, `GROUP BY`,
+/// aggregates or `.limit()`/`.offset()` (`ORA-02014`).
+pub trait OciLockingDsl: Query + Sized {
+    /// Lock the selected rows via `FOR UPDATE`
+    ///
+    /// By default oracle waits until conflicting locks are released, see
+    /// [`LockedQuery::no_wait`], [`LockedQuery::wait`] and [`LockedQuery::skip_locked`]
+    /// to change that.
+    fn lock_for_update(self) -> LockedQuery<Self> {
+        LockedQuery {
+            query: self,
+            modifier: LockModifier::None,
+        }
+    }
+}
+
+impl<T> OciLockingDsl for T where T: Query {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockModifier {
+    None,
+    NoWait,
+    Wait(u32),
+    SkipLocked,
+}
+
+/// A select statement with a `FOR UPDATE` clause, see [`OciLockingDsl`]
+#[derive(Debug, Clone, Copy)]
+pub struct LockedQuery<Q> {
+    query: Q,
+    modifier: LockModifier,
+}
+
+impl<Q> LockedQuery<Q> {
+    /// Fail with `ORA-00054` instead of waiting if a row is already locked
+    pub fn no_wait(self) -> Self {
+        LockedQuery {
+            modifier: LockModifier::NoWait,
+            ..self
+        }
+    }
+
+    /// Wait at most `seconds` for locked rows before failing with `ORA-30006`
+    pub fn wait(self, seconds: u32) -> Self {
+        LockedQuery {
+            modifier: LockModifier::Wait(seconds),
+            ..self
+        }
+    }
+
+    /// Skip rows that are already locked by another transaction
+    pub fn skip_locked(self) -> Self {
+        LockedQuery {
+            modifier: LockModifier::SkipLocked,
+            ..self
+        }
+    }
+
+    /// Lock the selected rows and return their current values
+    ///
+    /// This behaves like [`RunQueryDsl::load`], but fails with
+    /// [`Error::NotInTransaction`] instead of executing the query if `conn`
+    /// is not inside of a transaction, as the locks would be released
+    /// immediately otherwise. The returned values cannot be changed by other
+    /// transactions until the current transaction ends.
+    ///
+    /// Combined with [`skip_locked`](Self::skip_locked) this allows several
+    /// workers to claim rows of a queue table without blocking each other:
+    ///
+    /// ```no_run
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::OciConnection;
+    /// # use diesel_oci::query_builder::OciLockingDsl;
+    /// # table! { jobs { id -> Integer, done -> Bool, } }
+    /// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+    /// conn.transaction(|conn| {
+    ///     let claimed = jobs::table
+    ///         .filter(jobs::done.eq(false))
+    ///         .select(jobs::id)
+    ///         .lock_for_update()
+    ///         .skip_locked()
+    ///         .load_locked::<i32>(conn)?;
+    ///     // process the claimed jobs
+    ///     diesel::update(jobs::table.filter(jobs::id.eq_any(claimed)))
+    ///         .set(jobs::done.eq(true))
+    ///         .execute(conn)
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load_locked<'query, U>(self, conn: &mut OciConnection) -> QueryResult<Vec<U>>
+    where
+        Self: LoadQuery<'query, OciConnection, U>,
+    {
+        let depth =
+            <OciConnection as Connection>::TransactionManager::transaction_manager_status_mut(conn)
+                .transaction_depth()?;
+        if depth.is_none() {
+            return Err(Error::NotInTransaction);
+        }
+        self.load(conn)
+    }
+}
+
+impl<Q> QueryId for LockedQuery<Q> {
+    type QueryId = ();
+
+    // the lock modifier is part of the sql, but not part of the type
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q> Query for LockedQuery<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Q> QueryFragment<Oracle> for LockedQuery<Q>
+where
+    Q: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(" FOR UPDATE");
+        match self.modifier {
+            LockModifier::None => {}
+            LockModifier::NoWait => out.push_sql(" NOWAIT"),
+            LockModifier::Wait(seconds) => out.push_sql(&format!(" WAIT {}", seconds)),
+            LockModifier::SkipLocked => out.push_sql(" SKIP LOCKED"),
+        }
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for LockedQuery<Q> {}
@@ -6,18 +6,83 @@ use diesel::query_builder::QueryBuilder;
 use diesel::query_builder::QueryFragment;
 use diesel::result::Error as DieselError;
 
+use self::limit_offset::LimitOffsetPart;
+
 mod alias;
+mod array_comparison;
+mod dbms_lob_substr;
 mod exists;
+mod grouping_sets;
+mod hint;
+mod lateral;
+mod level;
 mod limit_offset;
+mod limit_percent;
+mod locking;
+mod nextval;
+mod order_siblings;
+mod paginate;
+mod regexp;
 mod returning;
+mod rowid;
+mod rownum;
+mod set_operation;
+mod statement_block;
+mod sys_connect_by_path;
+mod uncached;
+mod upsert;
+mod with_ties;
 
 pub use self::alias::Alias;
+pub use self::array_comparison::MAX_IN_LIST_SIZE;
+pub use self::dbms_lob_substr::{dbms_lob_substr, DbmsLobSubstr};
+pub use self::grouping_sets::{grand_total, grouping_sets, GrandTotal, GroupingSets};
+pub use self::hint::{HintDsl, Hinted};
+pub use self::lateral::{cross_apply, lateral, outer_apply, LateralJoin, LeftColumns};
+pub use self::level::{level, Level};
+pub use self::limit_percent::{LimitPercent, LimitPercentDsl};
+pub use self::locking::{LockedQuery, OciLockingDsl};
+pub use self::nextval::{nextval, NextVal};
+pub use self::order_siblings::{OrderSiblingsBy, OrderSiblingsDsl};
+pub use self::paginate::{paginate, Paginated, DEFAULT_PAGE_SIZE};
+pub use self::regexp::{
+    regexp_count, regexp_like, regexp_replace, regexp_substr, RegexpCount, RegexpLike,
+    RegexpReplace, RegexpSubstr,
+};
+pub use self::rowid::{rowid, RowId};
+pub use self::rownum::{rownum, Rownum};
+pub use self::set_operation::{
+    except, intersect, minus, union, union_all, Intersect, Minus, SetOperation, Union, UnionAll,
+};
+pub use self::statement_block::{statement_block, StatementBlock};
+pub use self::sys_connect_by_path::{sys_connect_by_path, SysConnectByPath};
+pub use self::uncached::{uncached, Uncached};
+pub use self::with_ties::{WithTies, WithTiesDsl};
+
+/// The maximal number of bind parameters oracle accepts for a single statement
+///
+/// This is the default for [`OciConnection::set_max_bind_count`](crate::OciConnection::set_max_bind_count)
+pub const DEFAULT_MAX_BIND_COUNT: usize = 65535;
 
 /// The Oracle query builder
+///
+/// Bind parameters are emitted as named placeholders `:in0`, `:in1`, … by
+/// default, see [`OciQueryBuilder::positional`] for numbered placeholders.
 #[derive(Default)]
 pub struct OciQueryBuilder {
     pub(crate) sql: String,
     bind_idx: u32,
+    positional: bool,
+    legacy_limit_offset: bool,
+    preserve_identifier_case: bool,
+    // the start of each select statement whose limit clause is not pushed yet
+    select_starts: Vec<usize>,
+    // whether the current limit clause has a limit and an offset
+    limit_offset: (bool, bool),
+    upsert: self::upsert::UpsertState,
+    // set if a part of the query cannot be written for oracle, as
+    // `push_sql` has no way to return an error
+    error: Option<String>,
 }
 
 impl OciQueryBuilder {
@@ -26,25 +91,132 @@ impl OciQueryBuilder {
         OciQueryBuilder {
             sql: String::new(),
             bind_idx: 0,
+            positional: false,
+            legacy_limit_offset: false,
+            preserve_identifier_case: false,
+            select_starts: Vec::new(),
+            limit_offset: (false, false),
+            upsert: Default::default(),
+            error: None,
+        }
+    }
+
+    /// Constructs a new query builder emitting bind parameters as
+    /// positional placeholders `:1`, `:2`, …
+    ///
+    /// Connections use this builder if
+    /// [`OciConnection::set_positional_binds`](crate::OciConnection::set_positional_binds)
+    /// is enabled. The out binds of a `RETURNING` clause keep their
+    /// `:outN` names.
+    pub fn positional() -> Self {
+        OciQueryBuilder {
+            positional: true,
+            ..Self::new()
+        }
+    }
+
+    /// Emit `.limit()` and `.offset()` as subquery filtering on `ROWNUM`
+    /// instead of `OFFSET … ROWS FETCH NEXT … ROWS ONLY`
+    ///
+    /// Connections use this if
+    /// [`OciConnection::set_legacy_limit_offset`](crate::OciConnection::set_legacy_limit_offset)
+    /// is enabled.
+    ///
+    /// ```
+    /// # use diesel::prelude::*;
+    /// # use diesel::query_builder::{QueryBuilder, QueryFragment};
+    /// # use diesel_oci::Oracle;
+    /// # use diesel_oci::query_builder::OciQueryBuilder;
+    /// # table! { users { id -> Integer, } }
+    /// let query = users::table.select(users::id).order(users::id).limit(10);
+    /// let mut qb = OciQueryBuilder::new().with_legacy_limit_offset(true);
+    /// query.to_sql(&mut qb, &Oracle).unwrap();
+    /// assert_eq!(
+    ///     qb.finish(),
+    ///     "SELECT * FROM (SELECT \"USERS\".\"ID\" FROM \"USERS\" ORDER BY \"USERS\".\"ID\") \
+    ///      WHERE ROWNUM <= :in0"
+    /// );
+    /// ```
+    pub fn with_legacy_limit_offset(mut self, legacy_limit_offset: bool) -> Self {
+        self.legacy_limit_offset = legacy_limit_offset;
+        self
+    }
+
+    /// Emit identifiers with the case given by `table!` or `sql_name`
+    /// instead of converting them to upper case
+    ///
+    /// This is required for tables and columns created with quoted mixed
+    /// or lower case names, like `CREATE TABLE "myTable"`. Connections use
+    /// this if
+    /// [`OciConnection::set_preserve_identifier_case`](crate::OciConnection::set_preserve_identifier_case)
+    /// is enabled.
+    ///
+    /// ```
+    /// # use diesel::prelude::*;
+    /// # use diesel::query_builder::{QueryBuilder, QueryFragment};
+    /// # use diesel_oci::Oracle;
+    /// # use diesel_oci::query_builder::OciQueryBuilder;
+    /// # table! { #[sql_name = "myTable"] my_table (id) { #[sql_name = "myId"] id -> Integer, } }
+    /// let query = my_table::table.select(my_table::id);
+    /// let mut qb = OciQueryBuilder::new().with_preserved_identifier_case(true);
+    /// query.to_sql(&mut qb, &Oracle).unwrap();
+    /// assert_eq!(
+    ///     qb.finish(),
+    ///     "SELECT \"myTable\".\"myId\" FROM \"myTable\""
+    /// );
+    /// ```
+    pub fn with_preserved_identifier_case(mut self, preserve_identifier_case: bool) -> Self {
+        self.preserve_identifier_case = preserve_identifier_case;
+        self
+    }
+
+    /// The number of bind parameters pushed so far
+    pub(crate) fn bind_count(&self) -> usize {
+        self.bind_idx as usize
+    }
+
+    /// Fails the query with the given message, the first error is kept
+    fn set_error(&mut self, message: String) {
+        self.error.get_or_insert(message);
+    }
+
+    /// Returns an error if a part of the query pushed so far cannot be
+    /// written for oracle
+    pub(crate) fn check(&self) -> Result<(), DieselError> {
+        match &self.error {
+            Some(message) => Err(DieselError::QueryBuilderError(message.clone().into())),
+            None => Ok(()),
         }
     }
 }
 
 impl QueryBuilder<Oracle> for OciQueryBuilder {
     fn push_sql(&mut self, sql: &str) {
+        if let Some(part) = LimitOffsetPart::from_marker(sql) {
+            self.push_limit_offset(part);
+            return;
+        }
+        if self.push_upsert_sql(sql) {
+            return;
+        }
+        if self.legacy_limit_offset && sql == "SELECT " {
+            self.select_starts.push(self.sql.len());
+        }
         self.sql.push_str(sql);
     }
 
     fn push_identifier(&mut self, identifier: &str) -> Result<(), DieselError> {
-        // TODO: check if there is a better way for escaping strings
-        self.push_sql("\"");
-        self.push_sql(&identifier.replace('`', "``").to_uppercase());
-        self.push_sql("\"");
+        let identifier = quote(identifier, self.preserve_identifier_case)?;
+        self.push_sql(&identifier);
         Ok(())
     }
 
     fn push_bind_param(&mut self) {
-        let sql = format!(":in{}", self.bind_idx);
+        let sql = if self.positional {
+            format!(":{}", self.bind_idx + 1)
+        } else {
+            format!(":in{}", self.bind_idx)
+        };
         self.bind_idx += 1;
         self.push_sql(&sql);
     }
@@ -54,6 +226,74 @@ impl QueryBuilder<Oracle> for OciQueryBuilder {
     }
 }
 
+/// Quote an identifier the same way as it is done for generated queries
+///
+/// The identifier is converted to upper case, as oracle does for unquoted
+/// identifiers, and enclosed in double quotes. Oracle provides no way to
+/// escape a double quote inside of a quoted identifier (doubling it is not
+/// supported, unlike in other databases), so identifiers containing a
+/// double quote or a nul character are rejected.
+///
+/// This is meant for dynamically built sql, for example if a table name
+/// is read from a configuration file:
+///
+/// ```
+/// # use diesel_oci::query_builder::quote_identifier;
+/// assert_eq!(quote_identifier("users").unwrap(), "\"USERS\"");
+/// assert!(quote_identifier("users\" --").is_err());
+/// ```
+pub fn quote_identifier(identifier: &str) -> Result<String, DieselError> {
+    quote(identifier, false)
+}
+
+fn quote(identifier: &str, preserve_case: bool) -> Result<String, DieselError> {
+    if identifier.contains(['"', '\0']) {
+        return Err(DieselError::QueryBuilderError(
+            format!("Invalid identifier `{}`", identifier.escape_debug()).into(),
+        ));
+    }
+    if preserve_case {
+        Ok(format!("\"{}\"", identifier))
+    } else {
+        Ok(format!("\"{}\"", identifier.to_uppercase()))
+    }
+}
+
+// Splits a comma separated list, ignoring commas within parentheses,
+// quoted identifiers and string literals
+fn split_list(list: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (idx, c) in list.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                items.push(list[start..idx].trim().to_owned());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = list[start..].trim();
+    if !last.is_empty() {
+        items.push(last.to_owned());
+    }
+    items
+}
+
+/// Whether the given clause renders to an empty sql string
+fn is_empty<T: QueryFragment<Oracle>>(clause: &T) -> diesel::QueryResult<bool> {
+    let mut qb = OciQueryBuilder::new();
+    clause.to_sql(&mut qb, &Oracle)?;
+    Ok(qb.sql.trim().is_empty())
+}
+
 impl QueryFragment<Oracle, OracleDualForEmptySelectClause> for NoFromClause {
     fn walk_ast(&self, mut out: diesel::query_builder::AstPass<Oracle>) -> diesel::QueryResult<()> {
         out.push_sql(" FROM DUAL ");
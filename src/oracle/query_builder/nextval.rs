@@ -0,0 +1,92 @@
+use super::Oracle;
+
+use std::marker::PhantomData;
+
+use diesel::expression::{is_aggregate, AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::{SingleValue, SqlType};
+
+/// The next value of a sequence, `"SEQUENCE".NEXTVAL`
+///
+/// diesel has no notion of sequences, so this is the way to use them in
+/// insert values or queries. `ST` is the sql type of the column the value
+/// is used for. Sequences of other schemas are referenced via
+/// [`in_schema`](NextVal::in_schema), which qualifies the sequence as
+/// `"SCHEMA"."SEQUENCE".NEXTVAL`:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel::sql_types::BigInt;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::nextval;
+/// # table! { users { id -> BigInt, name -> Text, } }
+/// let query = diesel::insert_into(users::table).values((
+///     users::id.eq(nextval::<BigInt>("users_seq").in_schema("app_owner")),
+///     users::name.eq("Sean"),
+/// ));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "INSERT INTO \"USERS\" (\"ID\", \"NAME\") \
+///      VALUES (\"APP_OWNER\".\"USERS_SEQ\".NEXTVAL, :in0) -- binds: [\"Sean\"]"
+/// );
+/// ```
+///
+/// The names are quoted like other identifiers, see
+/// [`quote_identifier`](crate::query_builder::quote_identifier).
+/// Accessing a sequence of another schema requires the `SELECT` privilege
+/// on it.
+pub fn nextval<ST>(sequence: &str) -> NextVal<ST>
+where
+    ST: SqlType + SingleValue,
+{
+    NextVal {
+        schema: None,
+        sequence: sequence.to_owned(),
+        _marker: PhantomData,
+    }
+}
+
+/// The expression returned by [`nextval()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct NextVal<ST> {
+    schema: Option<String>,
+    sequence: String,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST> NextVal<ST> {
+    /// Qualify the sequence with the given schema
+    pub fn in_schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_owned());
+        self
+    }
+}
+
+impl<ST> Expression for NextVal<ST>
+where
+    ST: SqlType + SingleValue,
+{
+    type SqlType = ST;
+}
+
+impl<ST, QS> AppearsOnTable<QS> for NextVal<ST> where ST: SqlType + SingleValue {}
+
+impl<ST, QS> SelectableExpression<QS> for NextVal<ST> where ST: SqlType + SingleValue {}
+
+impl<ST, GB> ValidGrouping<GB> for NextVal<ST> {
+    type IsAggregate = is_aggregate::Never;
+}
+
+impl<ST> QueryFragment<Oracle> for NextVal<ST> {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if let Some(schema) = &self.schema {
+            out.push_identifier(schema)?;
+            out.push_sql(".");
+        }
+        out.push_identifier(&self.sequence)?;
+        out.push_sql(".NEXTVAL");
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use super::{is_empty, Oracle};
+
+use diesel::expression::Expression;
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId, SelectStatement};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::{Error, QueryResult};
+
+/// Adds an `ORDER SIBLINGS BY` clause to a hierarchical query
+///
+/// In contrast to a plain `ORDER BY`, which orders the whole result and
+/// destroys the tree order of a `CONNECT BY` query, `ORDER SIBLINGS BY`
+/// only orders the rows with the same parent, so that children are still
+/// returned directly after their parent.
+pub trait OrderSiblingsDsl: Sized {
+    /// Order the siblings of each level of the hierarchy by `expr`
+    ///
+    /// `expr` can be a single expression or a tuple of expressions, each
+    /// of them optionally wrapped into `.asc()`/`.desc()`.
+    ///
+    /// The query must not contain an `ORDER BY`, `LIMIT`/`OFFSET` or
+    /// `FOR UPDATE` clause, as oracle expects these after the
+    /// `ORDER SIBLINGS BY` clause. Executing such a query returns an error.
+    fn order_siblings_by<E>(self, expr: E) -> OrderSiblingsBy<Self, E>
+    where
+        E: Expression,
+    {
+        OrderSiblingsBy { query: self, expr }
+    }
+}
+
+impl<F, S, D, W, O, LOf, G, H, LC> OrderSiblingsDsl
+    for SelectStatement<F, S, D, W, O, LOf, G, H, LC>
+{
+}
+
+/// A query ordered via `ORDER SIBLINGS BY`, see [`OrderSiblingsDsl`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct OrderSiblingsBy<Q, E> {
+    query: Q,
+    expr: E,
+}
+
+impl<Q, E> Query for OrderSiblingsBy<Q, E>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<F, S, D, W, O, LOf, G, H, LC, E> QueryFragment<Oracle>
+    for OrderSiblingsBy<SelectStatement<F, S, D, W, O, LOf, G, H, LC>, E>
+where
+    SelectStatement<F, S, D, W, O, LOf, G, H, LC>: QueryFragment<Oracle>,
+    O: QueryFragment<Oracle>,
+    LOf: QueryFragment<Oracle>,
+    LC: QueryFragment<Oracle>,
+    E: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        if !is_empty(&self.query.order)?
+            || !is_empty(&self.query.limit_offset)?
+            || !is_empty(&self.query.locking)?
+        {
+            return Err(Error::QueryBuilderError(
+                "`ORDER SIBLINGS BY` cannot be combined with `ORDER BY`, \
+                 `LIMIT`/`OFFSET` or `FOR UPDATE` in the same query"
+                    .into(),
+            ));
+        }
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(" ORDER SIBLINGS BY ");
+        self.expr.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Q, E, Conn> RunQueryDsl<Conn> for OrderSiblingsBy<Q, E> {}
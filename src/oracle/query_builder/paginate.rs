@@ -0,0 +1,120 @@
+use super::Oracle;
+use crate::oracle::connection::OciConnection;
+
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::query_dsl::{LoadQuery, RunQueryDsl};
+use diesel::result::QueryResult;
+use diesel::sql_types::BigInt;
+
+/// The page size used by [`paginate()`] if neither [`Paginated::per_page`]
+/// nor [`OciConnection::set_default_page_size`] is used
+pub const DEFAULT_PAGE_SIZE: i64 = 10;
+
+/// Load a single page of the result of the given query
+///
+/// Pages are numbered starting with 1. This wraps the query into
+///
+/// ```sql
+/// SELECT t.*, COUNT(*) OVER () FROM (<query>) t OFFSET :offset ROWS FETCH NEXT :per_page ROWS ONLY
+/// ```
+///
+/// so the total number of rows is computed by the same statement. The
+/// wrapped query should have an `ORDER BY` clause, otherwise the pages are
+/// not guaranteed to be stable. With
+/// [`OciConnection::set_legacy_limit_offset`] the page is selected via
+/// `ROWNUM` instead.
+///
+/// ```no_run
+/// # use diesel::prelude::*;
+/// # use diesel_oci::OciConnection;
+/// # use diesel_oci::query_builder::paginate;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+/// let (names, total) = paginate(users::table.select(users::name).order(users::id), 2)
+///     .per_page(25)
+///     .load_with_total::<String>(conn)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<Q>(query: Q, page: i64) -> Paginated<Q> {
+    Paginated {
+        query,
+        page,
+        per_page: DEFAULT_PAGE_SIZE,
+        offset: offset(page, DEFAULT_PAGE_SIZE),
+        explicit_per_page: false,
+    }
+}
+
+fn offset(page: i64, per_page: i64) -> i64 {
+    (page.max(1) - 1).saturating_mul(per_page)
+}
+
+/// The query returned by [`paginate()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Paginated<Q> {
+    query: Q,
+    page: i64,
+    per_page: i64,
+    offset: i64,
+    explicit_per_page: bool,
+}
+
+impl<Q> Paginated<Q> {
+    /// Set the number of rows per page
+    ///
+    /// If not set the default page size of the connection is used,
+    /// see [`OciConnection::set_default_page_size`]
+    pub fn per_page(self, per_page: i64) -> Self {
+        Paginated {
+            per_page,
+            offset: offset(self.page, per_page),
+            explicit_per_page: true,
+            ..self
+        }
+    }
+
+    /// Load the requested page together with the total number of rows
+    /// returned by the wrapped query
+    ///
+    /// The total is reported as 0 if the requested page is empty.
+    pub fn load_with_total<'a, U>(self, conn: &mut OciConnection) -> QueryResult<(Vec<U>, i64)>
+    where
+        Self: LoadQuery<'a, OciConnection, (U, i64)>,
+    {
+        let query = if self.explicit_per_page {
+            self
+        } else {
+            let per_page = conn.default_page_size();
+            self.per_page(per_page)
+        };
+        let results = query.load::<(U, i64)>(conn)?;
+        let total = results.first().map(|(_, total)| *total).unwrap_or(0);
+        let records = results.into_iter().map(|(record, _)| record).collect();
+        Ok((records, total))
+    }
+}
+
+impl<Q> Query for Paginated<Q>
+where
+    Q: Query,
+{
+    type SqlType = (Q::SqlType, BigInt);
+}
+
+impl<Q> QueryFragment<Oracle> for Paginated<Q>
+where
+    Q: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        // pushed separately, so that the legacy limit/offset syntax finds
+        // the start of the statement
+        out.push_sql("SELECT ");
+        out.push_sql("t.*, COUNT(*) OVER () FROM (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(") t");
+        super::limit_offset::walk_offset_limit(&self.offset, &self.per_page, out)
+    }
+}
+
+impl<Q> RunQueryDsl<OciConnection> for Paginated<Q> {}
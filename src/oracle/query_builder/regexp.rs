@@ -0,0 +1,461 @@
+use super::Oracle;
+
+use diesel::expression::{AppearsOnTable, AsExpression, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::{Bool, Integer, MaybeNullableType, Nullable, SqlType, Text};
+
+/// The oracle `REGEXP_LIKE` condition
+///
+/// Matches `source` against the regular expression `pattern`:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::regexp_like;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// let query = users::table
+///     .filter(regexp_like(users::name, "^s(ea|ie)n$").match_parameter("i"))
+///     .select(users::id);
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT \"USERS\".\"ID\" FROM \"USERS\" \
+///      WHERE REGEXP_LIKE(\"USERS\".\"NAME\", :in0, :in1) -- binds: [\"^s(ea|ie)n$\", \"i\"]"
+/// );
+/// ```
+///
+/// `REGEXP_LIKE` is a condition and not a function, so it can only be
+/// used in `WHERE` and `HAVING` clauses, but not in the select clause.
+pub fn regexp_like<S, P>(source: S, pattern: P) -> RegexpLike<S, P::Expression>
+where
+    S: Expression,
+    P: AsExpression<Text>,
+{
+    RegexpLike {
+        source,
+        pattern: pattern.as_expression(),
+        match_parameter: None,
+    }
+}
+
+/// The oracle `REGEXP_SUBSTR` function
+///
+/// Returns the part of `source` matching the regular expression `pattern`,
+/// or `NULL` if there is no match:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::regexp_substr;
+/// # table! { users { id -> Integer, email -> Text, } }
+/// // the domain of each email address
+/// let query = users::table.select(
+///     regexp_substr(users::email, "@(.*)$")
+///         .match_parameter("i")
+///         .subexpression(1),
+/// );
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT REGEXP_SUBSTR(\"USERS\".\"EMAIL\", :in0, :in1, :in2, :in3, :in4) \
+///      FROM \"USERS\" -- binds: [\"@(.*)$\", 1, 1, \"i\", 1]"
+/// );
+///
+/// Arguments in front of the last given one are rendered with their
+/// default values, as oracle only accepts them positionally.
+/// ```
+pub fn regexp_substr<S, P>(source: S, pattern: P) -> RegexpSubstr<S, P::Expression>
+where
+    S: Expression,
+    P: AsExpression<Text>,
+{
+    RegexpSubstr {
+        source,
+        pattern: pattern.as_expression(),
+        position: None,
+        occurrence: None,
+        match_parameter: None,
+        subexpression: None,
+    }
+}
+
+/// The oracle `REGEXP_REPLACE` function
+///
+/// Replaces the parts of `source` matching the regular expression `pattern`
+/// by `replacement`, which may refer to subexpressions via `\1` to `\9`:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::regexp_replace;
+/// # table! { users { id -> Integer, phone -> Text, } }
+/// let query = users::table.select(regexp_replace(users::phone, "[^0-9]", ""));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT REGEXP_REPLACE(\"USERS\".\"PHONE\", :in0, :in1) \
+///      FROM \"USERS\" -- binds: [\"[^0-9]\", \"\"]"
+/// );
+/// ```
+///
+/// By default all matches are replaced. The result is `NULL` if it is
+/// empty, as oracle does not distinguish empty strings from `NULL`.
+pub fn regexp_replace<S, P, R>(
+    source: S,
+    pattern: P,
+    replacement: R,
+) -> RegexpReplace<S, P::Expression, R::Expression>
+where
+    S: Expression,
+    P: AsExpression<Text>,
+    R: AsExpression<Text>,
+{
+    RegexpReplace {
+        source,
+        pattern: pattern.as_expression(),
+        replacement: replacement.as_expression(),
+        position: None,
+        occurrence: None,
+        match_parameter: None,
+    }
+}
+
+/// The oracle `REGEXP_COUNT` function
+///
+/// Returns how often the regular expression `pattern` matches in `source`:
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::regexp_count;
+/// # table! { posts { id -> Integer, body -> Text, } }
+/// let query = posts::table.select(regexp_count(posts::body, "diesel").match_parameter("i"));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "SELECT REGEXP_COUNT(\"POSTS\".\"BODY\", :in0, :in1, :in2) \
+///      FROM \"POSTS\" -- binds: [\"diesel\", 1, \"i\"]"
+/// );
+/// ```
+pub fn regexp_count<S, P>(source: S, pattern: P) -> RegexpCount<S, P::Expression>
+where
+    S: Expression,
+    P: AsExpression<Text>,
+{
+    RegexpCount {
+        source,
+        pattern: pattern.as_expression(),
+        position: None,
+        match_parameter: None,
+    }
+}
+
+/// The expression returned by [`regexp_like()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct RegexpLike<S, P> {
+    source: S,
+    pattern: P,
+    match_parameter: Option<String>,
+}
+
+/// The expression returned by [`regexp_substr()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct RegexpSubstr<S, P> {
+    source: S,
+    pattern: P,
+    position: Option<i32>,
+    occurrence: Option<i32>,
+    match_parameter: Option<String>,
+    subexpression: Option<i32>,
+}
+
+/// The expression returned by [`regexp_replace()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct RegexpReplace<S, P, R> {
+    source: S,
+    pattern: P,
+    replacement: R,
+    position: Option<i32>,
+    occurrence: Option<i32>,
+    match_parameter: Option<String>,
+}
+
+/// The expression returned by [`regexp_count()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct RegexpCount<S, P> {
+    source: S,
+    pattern: P,
+    position: Option<i32>,
+    match_parameter: Option<String>,
+}
+
+impl<S, P> RegexpLike<S, P> {
+    /// Change the matching behavior, for example `"i"` for case insensitive
+    /// matching or `"n"` to let `.` match newlines
+    pub fn match_parameter(mut self, match_parameter: &str) -> Self {
+        self.match_parameter = Some(match_parameter.to_owned());
+        self
+    }
+}
+
+impl<S, P> RegexpSubstr<S, P> {
+    /// Start searching at the 1-based character `position` of the source
+    pub fn position(mut self, position: i32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Return the `occurrence`-th match instead of the first one
+    pub fn occurrence(mut self, occurrence: i32) -> Self {
+        self.occurrence = Some(occurrence);
+        self
+    }
+
+    /// Change the matching behavior, see [`RegexpLike::match_parameter`]
+    pub fn match_parameter(mut self, match_parameter: &str) -> Self {
+        self.match_parameter = Some(match_parameter.to_owned());
+        self
+    }
+
+    /// Return only the part matched by the `subexpression`-th group of
+    /// the pattern instead of the whole match
+    pub fn subexpression(mut self, subexpression: i32) -> Self {
+        self.subexpression = Some(subexpression);
+        self
+    }
+}
+
+impl<S, P, R> RegexpReplace<S, P, R> {
+    /// Start searching at the 1-based character `position` of the source
+    pub fn position(mut self, position: i32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Replace only the `occurrence`-th match instead of all of them
+    pub fn occurrence(mut self, occurrence: i32) -> Self {
+        self.occurrence = Some(occurrence);
+        self
+    }
+
+    /// Change the matching behavior, see [`RegexpLike::match_parameter`]
+    pub fn match_parameter(mut self, match_parameter: &str) -> Self {
+        self.match_parameter = Some(match_parameter.to_owned());
+        self
+    }
+}
+
+impl<S, P> RegexpCount<S, P> {
+    /// Start counting at the 1-based character `position` of the source
+    pub fn position(mut self, position: i32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Change the matching behavior, see [`RegexpLike::match_parameter`]
+    pub fn match_parameter(mut self, match_parameter: &str) -> Self {
+        self.match_parameter = Some(match_parameter.to_owned());
+        self
+    }
+}
+
+// The optional trailing arguments of a regexp function
+//
+// Oracle only accepts them positionally, so an argument that is not set
+// is rendered with its default value if a later argument is set.
+#[derive(Clone, Copy)]
+enum OptionalArg<'a> {
+    Integer(&'a Option<i32>, &'static i32),
+    Text(&'a Option<String>, &'static str),
+}
+
+impl<'a> OptionalArg<'a> {
+    fn is_set(&self) -> bool {
+        match self {
+            OptionalArg::Integer(v, _) => v.is_some(),
+            OptionalArg::Text(v, _) => v.is_some(),
+        }
+    }
+}
+
+fn walk_optional_args<'b>(
+    args: &[OptionalArg<'b>],
+    mut out: AstPass<'_, 'b, Oracle>,
+) -> QueryResult<()> {
+    let count = args
+        .iter()
+        .rposition(|a| a.is_set())
+        .map_or(0, |idx| idx + 1);
+    for arg in &args[..count] {
+        out.push_sql(", ");
+        match *arg {
+            OptionalArg::Integer(v, default) => {
+                out.push_bind_param::<Integer, _>(v.as_ref().unwrap_or(default))?
+            }
+            OptionalArg::Text(v, default) => {
+                out.push_bind_param::<Text, _>(v.as_deref().unwrap_or(default))?
+            }
+        }
+    }
+    Ok(())
+}
+
+// `REGEXP_LIKE` and `REGEXP_COUNT` are `NULL` if the source is `NULL`
+type NullableIfSourceIs<S, T> =
+    <<<S as Expression>::SqlType as SqlType>::IsNull as MaybeNullableType<T>>::Out;
+
+impl<S, P> Expression for RegexpLike<S, P>
+where
+    S: Expression,
+    S::SqlType: SqlType,
+    <S::SqlType as SqlType>::IsNull: MaybeNullableType<Bool>,
+{
+    type SqlType = NullableIfSourceIs<S, Bool>;
+}
+
+impl<S, P> Expression for RegexpSubstr<S, P>
+where
+    S: Expression,
+{
+    type SqlType = Nullable<Text>;
+}
+
+impl<S, P, R> Expression for RegexpReplace<S, P, R>
+where
+    S: Expression,
+{
+    type SqlType = Nullable<Text>;
+}
+
+impl<S, P> Expression for RegexpCount<S, P>
+where
+    S: Expression,
+    S::SqlType: SqlType,
+    <S::SqlType as SqlType>::IsNull: MaybeNullableType<Integer>,
+{
+    type SqlType = NullableIfSourceIs<S, Integer>;
+}
+
+macro_rules! regexp_expression_impls {
+    ($name:ident <$source:ident $(, $arg:ident)+>) => {
+        impl<$source, $($arg,)+ QS> AppearsOnTable<QS> for $name<$source, $($arg,)+>
+        where
+            Self: Expression,
+            $source: AppearsOnTable<QS>,
+            $($arg: AppearsOnTable<QS>,)+
+        {
+        }
+
+        impl<$source, $($arg,)+ QS> SelectableExpression<QS> for $name<$source, $($arg,)+>
+        where
+            Self: AppearsOnTable<QS>,
+            $source: SelectableExpression<QS>,
+            $($arg: SelectableExpression<QS>,)+
+        {
+        }
+
+        // only the source can refer to grouped columns,
+        // the other arguments are usually constants
+        impl<$source, $($arg,)+ GB> ValidGrouping<GB> for $name<$source, $($arg,)+>
+        where
+            $source: ValidGrouping<GB>,
+        {
+            type IsAggregate = $source::IsAggregate;
+        }
+    };
+}
+
+regexp_expression_impls!(RegexpLike<S, P>);
+regexp_expression_impls!(RegexpSubstr<S, P>);
+regexp_expression_impls!(RegexpReplace<S, P, R>);
+regexp_expression_impls!(RegexpCount<S, P>);
+
+impl<S, P> QueryFragment<Oracle> for RegexpLike<S, P>
+where
+    S: QueryFragment<Oracle>,
+    P: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("REGEXP_LIKE(");
+        self.source.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.pattern.walk_ast(out.reborrow())?;
+        walk_optional_args(
+            &[OptionalArg::Text(&self.match_parameter, "")],
+            out.reborrow(),
+        )?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<S, P> QueryFragment<Oracle> for RegexpSubstr<S, P>
+where
+    S: QueryFragment<Oracle>,
+    P: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("REGEXP_SUBSTR(");
+        self.source.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.pattern.walk_ast(out.reborrow())?;
+        walk_optional_args(
+            &[
+                OptionalArg::Integer(&self.position, &1),
+                OptionalArg::Integer(&self.occurrence, &1),
+                OptionalArg::Text(&self.match_parameter, ""),
+                OptionalArg::Integer(&self.subexpression, &0),
+            ],
+            out.reborrow(),
+        )?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<S, P, R> QueryFragment<Oracle> for RegexpReplace<S, P, R>
+where
+    S: QueryFragment<Oracle>,
+    P: QueryFragment<Oracle>,
+    R: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("REGEXP_REPLACE(");
+        self.source.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.pattern.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.replacement.walk_ast(out.reborrow())?;
+        walk_optional_args(
+            &[
+                OptionalArg::Integer(&self.position, &1),
+                // 0 replaces all occurrences
+                OptionalArg::Integer(&self.occurrence, &0),
+                OptionalArg::Text(&self.match_parameter, ""),
+            ],
+            out.reborrow(),
+        )?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<S, P> QueryFragment<Oracle> for RegexpCount<S, P>
+where
+    S: QueryFragment<Oracle>,
+    P: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("REGEXP_COUNT(");
+        self.source.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.pattern.walk_ast(out.reborrow())?;
+        walk_optional_args(
+            &[
+                OptionalArg::Integer(&self.position, &1),
+                OptionalArg::Text(&self.match_parameter, ""),
+            ],
+            out.reborrow(),
+        )?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
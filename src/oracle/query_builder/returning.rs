@@ -1,51 +1,30 @@
 use crate::oracle::{backend::OracleReturningClause, Oracle};
+use diesel::expression::{Expression, QueryMetadata};
 use diesel::query_builder::{AstPass, QueryFragment, ReturningClause};
-use diesel::Column;
 
+// Oracle returns the values of a `RETURNING` clause via out binds. These are
+// named `:out0`, `:out1`, … and always placed at the end of the statement,
+// after all in binds of `INSERT`, `UPDATE` and `DELETE` statements. The
+// connection binds them in the same order, see `load_from_is_returning`.
 impl<Expr> QueryFragment<Oracle, OracleReturningClause> for ReturningClause<Expr>
 where
-    Expr: BindColumnList + QueryFragment<Oracle>,
+    Expr: Expression + QueryFragment<Oracle>,
+    Oracle: QueryMetadata<Expr::SqlType>,
 {
-    fn walk_ast<'b>(
-        &'b self,
-        mut out: diesel::query_builder::AstPass<'_, 'b, Oracle>,
-    ) -> diesel::QueryResult<()> {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> diesel::QueryResult<()> {
         out.push_sql(" RETURNING ");
         self.0.walk_ast(out.reborrow())?;
         out.push_sql(" INTO ");
-        Expr::bind_column_list(out)?;
-        Ok(())
-    }
-}
-
-pub trait BindColumnList {
-    fn bind_column_list(out: AstPass<Oracle>) -> diesel::QueryResult<()>;
-}
-
-macro_rules!  impl_bind_column_list {
-    ($(
-        $Tuple:tt {
-            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
-        }
-    )+) => {
-        $(
-            impl<$($T: Column,)+> BindColumnList for ($($T,)+) {
-                #[allow(unused_assignments)]
-                fn bind_column_list(mut out: AstPass<Oracle>) -> diesel::QueryResult<()> {
-                    let mut needs_comma = false;
-                    $(
-                        if needs_comma {
-                            out.push_sql(", ");
-                        }
-                        let placeholder = format!(":out{}", $idx);
-                        out.push_sql(&placeholder);
-                        needs_comma = true;
-                    )+
-                    Ok(())
-                }
+        // one out bind per returned value, independent of whether
+        // the clause contains a single expression or a tuple
+        let mut metadata = Vec::new();
+        Oracle::row_metadata(&mut (), &mut metadata);
+        for idx in 0..metadata.len() {
+            if idx > 0 {
+                out.push_sql(", ");
             }
-        )+
+            out.push_sql(&format!(":out{}", idx));
+        }
+        Ok(())
     }
 }
-
-diesel_derives::__diesel_for_each_tuple!(impl_bind_column_list);
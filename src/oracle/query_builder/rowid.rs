@@ -0,0 +1,71 @@
+use super::Oracle;
+use crate::oracle::types::Rowid;
+
+use diesel::expression::{is_aggregate, AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+
+/// The oracle `ROWID` pseudo column
+///
+/// The `ROWID` is the physical address of a row and the fastest way to
+/// access a single row. It has the sql type [`Rowid`](crate::Rowid), which
+/// is loaded in its textual representation and can be compared with such
+/// a string again, for example `.filter(rowid().eq(id))`.
+///
+/// Returning it from [`OciConnection::batch_insert_returning`](crate::OciConnection::batch_insert_returning)
+/// allows to update freshly inserted rows without looking them up by key:
+///
+/// ```no_run
+/// # use diesel::prelude::*;
+/// # use diesel_oci::OciConnection;
+/// # use diesel_oci::query_builder::rowid;
+/// # table! { users { id -> BigInt, name -> Text, } }
+/// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+/// let rowids: Vec<String> = conn.batch_insert_returning(
+///     diesel::insert_into(users::table)
+///         .values(vec![users::name.eq("Sean"), users::name.eq("Tess")])
+///         .returning(rowid()),
+/// )?;
+/// for rowid_value in &rowids {
+///     diesel::update(users::table.filter(rowid().eq(rowid_value)))
+///         .set(users::name.eq(users::name.concat(" (imported)")))
+///         .execute(conn)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See [`OciConnection::delete_by_rowids`](crate::OciConnection::delete_by_rowids)
+/// to delete a set of rows by their `ROWID`.
+///
+/// A `ROWID` is only stable as long as the row is not moved, for example
+/// by a table reorganisation, so it should not be stored permanently.
+/// In a join `ROWID` is ambiguous, so it can only be used for queries
+/// on a single table.
+pub fn rowid() -> RowId {
+    RowId
+}
+
+/// The expression returned by [`rowid()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct RowId;
+
+impl Expression for RowId {
+    type SqlType = Rowid;
+}
+
+impl<QS> AppearsOnTable<QS> for RowId {}
+
+impl<QS> SelectableExpression<QS> for RowId {}
+
+impl<GB> ValidGrouping<GB> for RowId {
+    type IsAggregate = is_aggregate::Never;
+}
+
+impl QueryFragment<Oracle> for RowId {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("ROWID");
+        Ok(())
+    }
+}
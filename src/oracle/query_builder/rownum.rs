@@ -0,0 +1,45 @@
+use super::Oracle;
+
+use diesel::expression::{is_aggregate, AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::BigInt;
+
+/// The oracle `ROWNUM` pseudo column
+///
+/// This can be used to restrict the number of returned rows in legacy
+/// style queries, for example `.filter(rownum().le(10))`.
+///
+/// Be aware that `ROWNUM` is assigned *before* any `ORDER BY` clause
+/// is applied. `users.filter(rownum().le(10)).order(name)` returns
+/// 10 arbitrary rows ordered by name, not the first 10 names. Use
+/// `.limit()` (which emits `FETCH FIRST n ROWS ONLY`) or wrap the ordered
+/// query into a subquery (`SELECT * FROM (… ORDER BY name) WHERE ROWNUM <= 10`)
+/// to get a proper top-N result.
+pub fn rownum() -> Rownum {
+    Rownum
+}
+
+/// The expression returned by [`rownum()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Rownum;
+
+impl Expression for Rownum {
+    type SqlType = BigInt;
+}
+
+impl<QS> AppearsOnTable<QS> for Rownum {}
+
+impl<QS> SelectableExpression<QS> for Rownum {}
+
+impl<GB> ValidGrouping<GB> for Rownum {
+    type IsAggregate = is_aggregate::Never;
+}
+
+impl QueryFragment<Oracle> for Rownum {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("ROWNUM");
+        Ok(())
+    }
+}
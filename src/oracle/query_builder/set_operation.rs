@@ -0,0 +1,166 @@
+use super::Oracle;
+
+use diesel::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::QueryResult;
+
+// Diesel's `CombineDsl` (`.union()`, `.except()`, …) cannot be supported by
+// third party backends, as the wrapper around its queries does not expose
+// the wrapped query. These functions provide the same for oracle.
+
+/// Combine the rows of both queries via `UNION`, removing duplicates
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::union;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// # table! { admins { id -> Integer, name -> Text, } }
+/// let query = union(
+///     users::table.select(users::name),
+///     admins::table.select(admins::name),
+/// );
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+///     "(SELECT \"USERS\".\"NAME\" FROM \"USERS\") UNION \
+///      (SELECT \"ADMINS\".\"NAME\" FROM \"ADMINS\") -- binds: []"
+/// );
+/// ```
+///
+/// Like `DISTINCT`, all set operations treat `NULL` values as equal, so
+/// at most one row consisting of the same values and `NULL`s is returned.
+pub fn union<L, R>(lhs: L, rhs: R) -> SetOperation<Union, L::Query, R::Query>
+where
+    L: AsQuery,
+    R: AsQuery,
+{
+    SetOperation::new(Union, lhs, rhs)
+}
+
+/// Combine the rows of both queries via `UNION ALL`, keeping duplicates
+pub fn union_all<L, R>(lhs: L, rhs: R) -> SetOperation<UnionAll, L::Query, R::Query>
+where
+    L: AsQuery,
+    R: AsQuery,
+{
+    SetOperation::new(UnionAll, lhs, rhs)
+}
+
+/// Return the distinct rows returned by both queries via `INTERSECT`
+pub fn intersect<L, R>(lhs: L, rhs: R) -> SetOperation<Intersect, L::Query, R::Query>
+where
+    L: AsQuery,
+    R: AsQuery,
+{
+    SetOperation::new(Intersect, lhs, rhs)
+}
+
+/// Return the distinct rows of `lhs` not returned by `rhs`
+///
+/// Oracle calls this set operation `MINUS`, older versions do not
+/// understand the standard `EXCEPT`. See also [`except`].
+pub fn minus<L, R>(lhs: L, rhs: R) -> SetOperation<Minus, L::Query, R::Query>
+where
+    L: AsQuery,
+    R: AsQuery,
+{
+    SetOperation::new(Minus, lhs, rhs)
+}
+
+/// The standard name of [`minus`], rendered as `MINUS` as well
+pub fn except<L, R>(lhs: L, rhs: R) -> SetOperation<Minus, L::Query, R::Query>
+where
+    L: AsQuery,
+    R: AsQuery,
+{
+    minus(lhs, rhs)
+}
+
+/// A query combining two queries via a set operation, see [`union`],
+/// [`union_all`], [`intersect`] and [`minus`]
+///
+/// It can be used as input for further set operations.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct SetOperation<Op, L, R> {
+    operator: Op,
+    lhs: L,
+    rhs: R,
+}
+
+impl<Op, L, R> SetOperation<Op, L, R> {
+    fn new<A, B>(operator: Op, lhs: A, rhs: B) -> Self
+    where
+        A: AsQuery<Query = L>,
+        B: AsQuery<Query = R>,
+    {
+        SetOperation {
+            operator,
+            lhs: lhs.as_query(),
+            rhs: rhs.as_query(),
+        }
+    }
+}
+
+/// The `UNION` operator
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Union;
+
+/// The `UNION ALL` operator
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct UnionAll;
+
+/// The `INTERSECT` operator
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Intersect;
+
+/// The `MINUS` operator
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Minus;
+
+macro_rules! set_operator {
+    ($($op:ident => $sql:expr,)+) => {
+        $(
+            impl QueryFragment<Oracle> for $op {
+                fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+                    out.push_sql($sql);
+                    Ok(())
+                }
+            }
+        )+
+    }
+}
+
+set_operator! {
+    Union => " UNION ",
+    UnionAll => " UNION ALL ",
+    Intersect => " INTERSECT ",
+    Minus => " MINUS ",
+}
+
+impl<Op, L, R> Query for SetOperation<Op, L, R>
+where
+    L: Query,
+    R: Query<SqlType = L::SqlType>,
+{
+    type SqlType = L::SqlType;
+}
+
+impl<Op, L, R> QueryFragment<Oracle> for SetOperation<Op, L, R>
+where
+    Op: QueryFragment<Oracle>,
+    L: QueryFragment<Oracle>,
+    R: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        self.operator.walk_ast(out.reborrow())?;
+        out.push_sql("(");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<Op, L, R, Conn> RunQueryDsl<Conn> for SetOperation<Op, L, R> {}
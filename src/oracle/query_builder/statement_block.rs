@@ -0,0 +1,73 @@
+use super::Oracle;
+
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+
+/// Combines several statements into a single anonymous PL/SQL block
+///
+/// `statements` is a tuple of `INSERT`, `UPDATE` or `DELETE` statements
+/// (or raw [`sql_query`](diesel::sql_query) statements). They are rendered as
+///
+/// ```sql
+/// BEGIN
+/// <statement 1>;
+/// <statement 2>;
+/// END;
+/// ```
+///
+/// and share one set of bind parameters, so the whole block is executed
+/// in a single round trip. Use
+/// [`OciConnection::execute_statement_block`](crate::OciConnection::execute_statement_block)
+/// to execute the block.
+///
+/// ```
+/// # use diesel::prelude::*;
+/// # use diesel_oci::Oracle;
+/// # use diesel_oci::query_builder::statement_block;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// let block = statement_block((
+///     diesel::insert_into(users::table).values((users::id.eq(1), users::name.eq("Sean"))),
+///     diesel::delete(users::table.filter(users::id.eq(2))),
+/// ));
+/// assert_eq!(
+///     diesel::debug_query::<Oracle, _>(&block).to_string(),
+///     "BEGIN\n\
+///      INSERT INTO \"USERS\" (\"ID\", \"NAME\") VALUES (:in0, :in1);\n\
+///      DELETE FROM \"USERS\" WHERE \"USERS\".\"ID\" = :in2;\n\
+///      END; -- binds: [1, \"Sean\", 2]"
+/// );
+/// ```
+///
+/// Statements with a `RETURNING` clause and queries are not supported,
+/// as PL/SQL requires an `INTO` target for their results.
+pub fn statement_block<T>(statements: T) -> StatementBlock<T> {
+    StatementBlock(statements)
+}
+
+/// The return type of [`statement_block`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct StatementBlock<T>(T);
+
+macro_rules! statement_block_impls {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T: QueryFragment<Oracle>,)+> QueryFragment<Oracle> for StatementBlock<($($T,)+)> {
+                fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+                    out.push_sql("BEGIN\n");
+                    $(
+                        self.0.$idx.walk_ast(out.reborrow())?;
+                        out.push_sql(";\n");
+                    )+
+                    out.push_sql("END;");
+                    Ok(())
+                }
+            }
+        )+
+    }
+}
+
+diesel_derives::__diesel_for_each_tuple!(statement_block_impls);
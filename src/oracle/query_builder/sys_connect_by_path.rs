@@ -0,0 +1,64 @@
+use super::Oracle;
+
+use diesel::expression::{AppearsOnTable, Expression};
+use diesel::expression::{SelectableExpression, ValidGrouping};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Text;
+
+/// The oracle `SYS_CONNECT_BY_PATH` function
+///
+/// Returns the path from the root to the current row of a hierarchical
+/// (`CONNECT BY`) query, with every level prefixed by `separator`.
+///
+/// The separator is rendered as string literal, as oracle does not accept
+/// arbitrary expressions there. It must not occur in any value of `expr`,
+/// otherwise oracle reports `ORA-30004`.
+pub fn sys_connect_by_path<T>(expr: T, separator: &str) -> SysConnectByPath<T>
+where
+    T: Expression,
+{
+    SysConnectByPath {
+        expr,
+        separator: separator.to_owned(),
+    }
+}
+
+/// The expression returned by [`sys_connect_by_path()`]
+#[derive(Debug, Clone, QueryId)]
+pub struct SysConnectByPath<T> {
+    expr: T,
+    separator: String,
+}
+
+impl<T> Expression for SysConnectByPath<T>
+where
+    T: Expression,
+{
+    type SqlType = Text;
+}
+
+impl<T, QS> AppearsOnTable<QS> for SysConnectByPath<T> where T: AppearsOnTable<QS> {}
+
+impl<T, QS> SelectableExpression<QS> for SysConnectByPath<T> where T: SelectableExpression<QS> {}
+
+impl<T, GB> ValidGrouping<GB> for SysConnectByPath<T>
+where
+    T: ValidGrouping<GB>,
+{
+    type IsAggregate = T::IsAggregate;
+}
+
+impl<T> QueryFragment<Oracle> for SysConnectByPath<T>
+where
+    T: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.push_sql("SYS_CONNECT_BY_PATH(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", '");
+        out.push_sql(&self.separator.replace('\'', "''"));
+        out.push_sql("')");
+        Ok(())
+    }
+}
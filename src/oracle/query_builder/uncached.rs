@@ -0,0 +1,54 @@
+use super::Oracle;
+
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::QueryResult;
+
+/// Excludes the given query from the statement cache
+///
+/// By default every statement that is safe to cache is kept in the
+/// statement cache of the underlying connection, which also means oracle
+/// keeps reusing the same execution plan. For queries with highly variable
+/// plans (for example depending on skewed bind values) this is not always
+/// desired. Wrapping such a query in `uncached` forces a fresh statement
+/// for each execution:
+///
+/// ```no_run
+/// # use diesel::prelude::*;
+/// # use diesel_oci::OciConnection;
+/// # use diesel_oci::query_builder::uncached;
+/// # table! { users { id -> Integer, name -> Text, } }
+/// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+/// let names = uncached(users::table.filter(users::id.gt(42)).select(users::name))
+///     .load::<String>(conn)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn uncached<Q>(query: Q) -> Uncached<Q> {
+    Uncached { query }
+}
+
+/// The query returned by [`uncached()`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Uncached<Q> {
+    query: Q,
+}
+
+impl<Q> Query for Uncached<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Q> QueryFragment<Oracle> for Uncached<Q>
+where
+    Q: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        self.query.walk_ast(out.reborrow())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for Uncached<Q> {}
@@ -0,0 +1,212 @@
+use super::{split_list, OciQueryBuilder};
+
+// Oracle has no `INSERT … ON CONFLICT`, upserts are written as `MERGE`
+// statement instead. diesel only allows to render its `on_conflict` clauses
+// in the postgres like syntax, as the values, the conflict target and the
+// action of the clause are not accessible outside of diesel. So the backend
+// declares that syntax, and the query builder translates the statement into
+// a `MERGE` statement while it is pushed:
+//
+// INSERT INTO "T" ("ID", "NAME") VALUES (:in0, :in1)
+//     ON CONFLICT ("ID") DO UPDATE SET "NAME" = excluded."NAME"
+//
+// becomes
+//
+// MERGE INTO "T" USING (SELECT :in0 "ID", :in1 "NAME" FROM DUAL) "diesel_excluded"
+//     ON ("T"."ID" = "diesel_excluded"."ID")
+//     WHEN NOT MATCHED THEN INSERT ("ID", "NAME")
+//         VALUES ("diesel_excluded"."ID", "diesel_excluded"."NAME")
+//     WHEN MATCHED THEN UPDATE SET "NAME" = "diesel_excluded"."NAME"
+//
+// Oracle accepts both `WHEN` clauses in any order, so the update clause can
+// be pushed last without knowing where the statement ends. The binds keep
+// their order: the inserted values, followed by the values of the update.
+//
+// The rows of a query, `INSERT INTO "T" ("ID", "NAME") SELECT …`, are used
+// as source as they are. The query is named with a `WITH` clause, which
+// gives its columns the names of the inserted columns:
+//
+// MERGE INTO "T" USING (WITH "diesel_source" ("ID", "NAME") AS (SELECT …)
+//     SELECT * FROM "diesel_source") "diesel_excluded" ON (…
+
+// The fragments pushed by diesel for an upsert. These are not part of the
+// public api of diesel, so the version of diesel is pinned exactly and the
+// `upsert_diesel_fragments` test fails if one of them changes.
+const INSERT: &str = "INSERT";
+const ON_CONFLICT: &str = " ON CONFLICT";
+const DO_NOTHING: &str = " DO NOTHING";
+const DO_UPDATE: &str = " DO UPDATE SET ";
+const EXCLUDED: &str = "excluded.";
+
+const EXCLUDED_ALIAS: &str = "\"diesel_excluded\"";
+const SOURCE_ALIAS: &str = "\"diesel_source\"";
+
+#[derive(Default)]
+pub(super) struct UpsertState {
+    // the start of the last `INSERT` statement
+    insert_start: Option<usize>,
+    // set between `ON CONFLICT` and the action
+    pending: Option<PendingUpsert>,
+}
+
+struct PendingUpsert {
+    table: String,
+    // the inserted columns, without those set to `DEFAULT`
+    columns: Vec<String>,
+    target: String,
+}
+
+impl OciQueryBuilder {
+    /// Returns `true` if `sql` was handled as part of an upsert
+    pub(super) fn push_upsert_sql(&mut self, sql: &str) -> bool {
+        if let Some(pending) = &mut self.upsert.pending {
+            if sql == DO_NOTHING || sql == DO_UPDATE {
+                let pending = self.upsert.pending.take().expect("Checked above");
+                self.push_merge_actions(pending, sql == DO_UPDATE);
+            } else {
+                pending.target.push_str(sql);
+            }
+            return true;
+        }
+        match sql {
+            INSERT => {
+                self.upsert.insert_start = Some(self.sql.len());
+                false
+            }
+            ON_CONFLICT => self.start_merge(),
+            EXCLUDED if self.upsert.insert_start.is_some() => {
+                self.sql.push_str(EXCLUDED_ALIAS);
+                self.sql.push('.');
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Replaces the `INSERT` statement pushed so far with the start of the
+    // `MERGE` statement. The values of a single row or a query can be used
+    // as source, other statements, like `INSERT … DEFAULT VALUES`, fail the
+    // query.
+    fn start_merge(&mut self) -> bool {
+        let insert_start = match self.upsert.insert_start {
+            Some(insert_start) => insert_start,
+            None => return false,
+        };
+        let (table, columns, source) = match parse_insert(&self.sql[insert_start..]) {
+            Some(insert) => insert,
+            None => {
+                self.set_error(
+                    "Upserts via `on_conflict` are only supported for inserts \
+                     of a single row with explicit values or of the rows of a query"
+                        .to_owned(),
+                );
+                return false;
+            }
+        };
+        let (columns, source) = match source {
+            InsertSource::Values(values) => {
+                // omitted columns get their default value as well
+                let (columns, values): (Vec<_>, Vec<_>) = columns
+                    .into_iter()
+                    .zip(values)
+                    .filter(|(_, value)| !value.eq_ignore_ascii_case("DEFAULT"))
+                    .unzip();
+                let source = columns
+                    .iter()
+                    .zip(&values)
+                    .map(|(column, value)| format!("{} {}", value, column))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                (columns, format!("SELECT {} FROM DUAL", source))
+            }
+            InsertSource::Query(query) => {
+                let source = format!(
+                    "WITH {alias} ({}) AS ({}) SELECT * FROM {alias}",
+                    columns.join(", "),
+                    query,
+                    alias = SOURCE_ALIAS
+                );
+                (columns, source)
+            }
+        };
+
+        self.sql.truncate(insert_start);
+        self.sql.push_str(&format!(
+            "MERGE INTO {} USING ({}) {} ON (",
+            table, source, EXCLUDED_ALIAS
+        ));
+        self.upsert.pending = Some(PendingUpsert {
+            table,
+            columns,
+            target: String::new(),
+        });
+        true
+    }
+
+    fn push_merge_actions(&mut self, pending: PendingUpsert, update: bool) {
+        // the conflict target is pushed as ` ("A", "B")`
+        let target = pending.target.trim();
+        let condition = match target.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            Some(columns) => split_list(columns)
+                .iter()
+                .map(|c| format!("{}.{} = {}.{}", pending.table, c, EXCLUDED_ALIAS, c))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+            // no target or `ON CONSTRAINT`, which cannot be expressed as
+            // join condition
+            None => {
+                self.set_error(
+                    "Upserts via `on_conflict` need the columns of the conflict target".to_owned(),
+                );
+                target.to_owned()
+            }
+        };
+        let inserted = pending
+            .columns
+            .iter()
+            .map(|c| format!("{}.{}", EXCLUDED_ALIAS, c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.sql.push_str(&format!(
+            "{}) WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+            condition,
+            pending.columns.join(", "),
+            inserted
+        ));
+        if update {
+            self.sql.push_str(" WHEN MATCHED THEN UPDATE SET ");
+        }
+    }
+}
+
+// The rows inserted by an `INSERT` statement
+enum InsertSource {
+    // the values of a single row, one per column
+    Values(Vec<String>),
+    // a query returning a value per column
+    Query(String),
+}
+
+// Splits `INSERT INTO "T" ("A", "B") VALUES (:in0, :in1)` or
+// `INSERT INTO "T" ("A", "B") SELECT …` into the table, the columns and
+// the source of the inserted rows
+fn parse_insert(sql: &str) -> Option<(String, Vec<String>, InsertSource)> {
+    let rest = sql.strip_prefix("INSERT INTO ")?;
+    let (table, rest) = rest.split_once(" (")?;
+    let (columns, source) = if let Some((columns, values)) = rest.split_once(") VALUES (") {
+        let values = split_list(values.strip_suffix(')')?);
+        (split_list(columns), InsertSource::Values(values))
+    } else {
+        let (columns, query) = rest.split_once(") SELECT ")?;
+        let query = format!("SELECT {}", query);
+        (split_list(columns), InsertSource::Query(query))
+    };
+    let complete = match &source {
+        InsertSource::Values(values) => columns.len() == values.len(),
+        InsertSource::Query(_) => true,
+    };
+    if columns.is_empty() || !complete {
+        return None;
+    }
+    Some((table.to_owned(), columns, source))
+}
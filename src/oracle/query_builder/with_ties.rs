@@ -0,0 +1,107 @@
+use super::limit_offset::LimitOffsetPart;
+use super::{is_empty, Oracle};
+
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId, SelectStatement};
+use diesel::query_builder::{LimitClause, LimitOffsetClause};
+use diesel::query_dsl::RunQueryDsl;
+use diesel::result::{Error, QueryResult};
+
+/// Includes rows tied with the last row of a `.limit()` via
+/// `FETCH FIRST … ROWS WITH TIES`
+pub trait WithTiesDsl: Sized {
+    /// Also return all rows that have the same values for the `ORDER BY`
+    /// expressions as the last row within the limit
+    ///
+    /// This is only available for queries with a `.limit()` and the query
+    /// needs an `ORDER BY` clause to define ties. Executing a query without
+    /// `.order()` returns an error, as well as executing it with
+    /// [`OciConnection::set_legacy_limit_offset`](crate::OciConnection::set_legacy_limit_offset).
+    ///
+    /// ```
+    /// # use diesel::prelude::*;
+    /// # use diesel_oci::Oracle;
+    /// # use diesel_oci::query_builder::WithTiesDsl;
+    /// # table! { users { id -> Integer, score -> Integer, } }
+    /// let query = users::table
+    ///     .select(users::id)
+    ///     .order(users::score.desc())
+    ///     .limit(3)
+    ///     .with_ties();
+    /// assert_eq!(
+    ///     diesel::debug_query::<Oracle, _>(&query).to_string(),
+    ///     "SELECT \"USERS\".\"ID\" FROM \"USERS\" ORDER BY \"USERS\".\"SCORE\" DESC \
+    ///      FETCH FIRST :in0 ROWS WITH TIES -- binds: [3]"
+    /// );
+    /// ```
+    fn with_ties(self) -> WithTies<Self> {
+        WithTies { query: self }
+    }
+}
+
+impl<F, S, D, W, O, L, Of, G, H, LC> WithTiesDsl
+    for SelectStatement<F, S, D, W, O, LimitOffsetClause<LimitClause<L>, Of>, G, H, LC>
+{
+}
+
+/// A query including the rows tied with the last row of its limit, see [`WithTiesDsl`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct WithTies<Q> {
+    query: Q,
+}
+
+impl<Q> Query for WithTies<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<F, S, D, W, O, L, Of, G, H, LC> QueryFragment<Oracle>
+    for WithTies<SelectStatement<F, S, D, W, O, LimitOffsetClause<LimitClause<L>, Of>, G, H, LC>>
+where
+    F: QueryFragment<Oracle>,
+    S: QueryFragment<Oracle>,
+    D: QueryFragment<Oracle>,
+    W: QueryFragment<Oracle>,
+    O: QueryFragment<Oracle>,
+    L: QueryFragment<Oracle>,
+    Of: QueryFragment<Oracle>,
+    G: QueryFragment<Oracle>,
+    H: QueryFragment<Oracle>,
+    LC: QueryFragment<Oracle>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Oracle>) -> QueryResult<()> {
+        let query = &self.query;
+        if is_empty(&query.order)? {
+            return Err(Error::QueryBuilderError(
+                "`.with_ties()` requires an `ORDER BY` clause, \
+                 use `.order()` to define which rows are tied"
+                    .into(),
+            ));
+        }
+        // same as the select statement itself, only the limit clause differs
+        out.push_sql("SELECT ");
+        query.distinct.walk_ast(out.reborrow())?;
+        query.select.walk_ast(out.reborrow())?;
+        query.from.walk_ast(out.reborrow())?;
+        query.where_clause.walk_ast(out.reborrow())?;
+        query.group_by.walk_ast(out.reborrow())?;
+        query.having.walk_ast(out.reborrow())?;
+        query.order.walk_ast(out.reborrow())?;
+        super::limit_offset::walk_without_limit_offset(out.reborrow())?;
+        super::limit_offset::walk_requires_fetch(LimitOffsetPart::WithTies, out.reborrow());
+        let offset = &query.limit_offset.offset_clause;
+        if is_empty(offset)? {
+            out.push_sql(" FETCH FIRST ");
+        } else {
+            offset.walk_ast(out.reborrow())?;
+            out.push_sql(" ROWS FETCH NEXT ");
+        }
+        query.limit_offset.limit_clause.0.walk_ast(out.reborrow())?;
+        out.push_sql(" ROWS WITH TIES");
+        query.locking.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for WithTies<Q> {}
@@ -0,0 +1,51 @@
+extern crate bigdecimal;
+
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Numeric;
+use std::str::FromStr;
+
+use self::bigdecimal::BigDecimal;
+
+use super::primitives::NumberWrapper;
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::bind_collector::BindValue;
+use crate::oracle::connection::{InnerValue, OracleValue};
+
+/// Parses the textual representation of a oracle `NUMBER`
+///
+/// Oracle returns all significant digits (up to 40) of a `NUMBER` as text,
+/// so no digit is lost or rounded while loading a value as `BigDecimal`.
+fn parse_big_decimal(s: &str) -> deserialize::Result<BigDecimal> {
+    BigDecimal::from_str(s).map_err(|e| {
+        format!(
+            "Numeric value `{}` cannot be represented as `BigDecimal`: {}",
+            s, e
+        )
+        .into()
+    })
+}
+
+impl FromSql<Numeric, Oracle> for BigDecimal {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                let s = <String as oracle::sql_type::FromSql>::from_sql(raw_value)?;
+                parse_big_decimal(&s)
+            }
+            InnerValue::Numeric(s) => parse_big_decimal(&s),
+            _ => Err("Got invalid value for BigDecimal".into()),
+        }
+    }
+}
+
+/// Values are bound as text, so they are converted to `NUMBER` by oracle
+///
+/// Oracle rounds values with more than 38 significant digits, values
+/// outside of the range of `NUMBER` result in an error.
+impl ToSql<Numeric, Oracle> for BigDecimal {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        out.set_value(BindValue::Owned(Box::new(NumberWrapper(self.to_string()))));
+        Ok(IsNull::No)
+    }
+}
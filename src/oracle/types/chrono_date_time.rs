@@ -1,5 +1,7 @@
 extern crate chrono_time as chrono;
 use diesel::deserialize::FromSql;
+use diesel::expression::AsExpression;
+use diesel::internal::derives::as_expression::Bound;
 use diesel::serialize::{IsNull, Output, ToSql};
 use diesel::sql_types::*;
 use std::error::Error;
@@ -8,9 +10,10 @@ use crate::oracle::backend::Oracle;
 
 use crate::oracle::connection::bind_collector::BindValue;
 
-use self::chrono::{NaiveDate, NaiveDateTime};
+use self::chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc};
 
 use super::super::connection::{InnerValue, OracleValue};
+use super::{IntervalDayToSecond, TimestampWithTimeZone};
 
 impl FromSql<Timestamp, Oracle> for NaiveDateTime {
     fn from_sql(bytes: OracleValue<'_>) -> Result<Self, Box<dyn Error + Send + Sync>> {
@@ -24,6 +27,10 @@ impl FromSql<Timestamp, Oracle> for NaiveDateTime {
     }
 }
 
+/// Values are bound with nanosecond precision, independent of the precision
+/// of the column. Oracle rounds them to the fractional seconds precision of
+/// the column when storing them, e.g. to milliseconds for a `TIMESTAMP(3)`
+/// column, so there is no need to specify the precision of a bind.
 impl ToSql<Timestamp, Oracle> for NaiveDateTime {
     fn to_sql<'b>(
         &'b self,
@@ -55,3 +62,114 @@ impl ToSql<Date, Oracle> for NaiveDate {
         Ok(IsNull::No)
     }
 }
+
+/// Values keep their time zone offset, in contrast to converting
+/// them to UTC or the local time zone
+impl FromSql<TimestampWithTimeZone, Oracle> for DateTime<FixedOffset> {
+    fn from_sql(bytes: OracleValue<'_>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        match bytes.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+            }
+            InnerValue::TimestampTz(t) => Ok(t),
+            _ => Err("Invalid value for timestamp with time zone".into()),
+        }
+    }
+}
+
+impl ToSql<TimestampWithTimeZone, Oracle> for DateTime<FixedOffset> {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Oracle>,
+    ) -> Result<IsNull, Box<dyn Error + Send + Sync>> {
+        out.set_value(BindValue::Borrowed(self));
+        Ok(IsNull::No)
+    }
+}
+
+/// Values are converted to UTC, the original offset is lost
+impl FromSql<TimestampWithTimeZone, Oracle> for DateTime<Utc> {
+    fn from_sql(bytes: OracleValue<'_>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        <DateTime<FixedOffset> as FromSql<TimestampWithTimeZone, Oracle>>::from_sql(bytes)
+            .map(|t| t.with_timezone(&Utc))
+    }
+}
+
+impl ToSql<TimestampWithTimeZone, Oracle> for DateTime<Utc> {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Oracle>,
+    ) -> Result<IsNull, Box<dyn Error + Send + Sync>> {
+        out.set_value(BindValue::Borrowed(self));
+        Ok(IsNull::No)
+    }
+}
+
+// `#[derive(AsExpression)]` cannot be used here, as it implements
+// `AsExpression<Nullable<_>>` as well, which the orphan rules forbid for
+// a foreign type. Use `Some(value)` to compare with nullable columns.
+macro_rules! timestamp_with_time_zone_as_expression {
+    ($($tz: ty),*) => {$(
+        impl AsExpression<TimestampWithTimeZone> for DateTime<$tz> {
+            type Expression = Bound<TimestampWithTimeZone, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a> AsExpression<TimestampWithTimeZone> for &'a DateTime<$tz> {
+            type Expression = Bound<TimestampWithTimeZone, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    )*};
+}
+
+timestamp_with_time_zone_as_expression!(FixedOffset, Utc);
+
+/// All components of a negative interval are negative, so the sum of the
+/// components gives the duration for both signs
+impl FromSql<IntervalDayToSecond, Oracle> for Duration {
+    fn from_sql(bytes: OracleValue<'_>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        match bytes.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+            }
+            InnerValue::IntervalDS(i) => Ok(Duration::days(i64::from(i.days()))
+                + Duration::hours(i64::from(i.hours()))
+                + Duration::minutes(i64::from(i.minutes()))
+                + Duration::seconds(i64::from(i.seconds()))
+                + Duration::nanoseconds(i64::from(i.nanoseconds()))),
+            _ => Err("Invalid value for a day to second interval".into()),
+        }
+    }
+}
+
+impl ToSql<IntervalDayToSecond, Oracle> for Duration {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Oracle>,
+    ) -> Result<IsNull, Box<dyn Error + Send + Sync>> {
+        out.set_value(BindValue::Borrowed(self));
+        Ok(IsNull::No)
+    }
+}
+
+impl AsExpression<IntervalDayToSecond> for Duration {
+    type Expression = Bound<IntervalDayToSecond, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<IntervalDayToSecond> for &'a Duration {
+    type Expression = Bound<IntervalDayToSecond, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
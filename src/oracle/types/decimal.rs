@@ -0,0 +1,52 @@
+extern crate rust_decimal;
+
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Numeric;
+use std::str::FromStr;
+
+use self::rust_decimal::Decimal;
+
+use super::primitives::NumberWrapper;
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::bind_collector::BindValue;
+use crate::oracle::connection::{InnerValue, OracleValue};
+
+/// Parses the textual representation of a oracle `NUMBER`
+///
+/// Fractional digits beyond the 28 digits supported by `Decimal` are
+/// rounded, values that exceed the range of `Decimal` result in an error.
+fn parse_decimal(s: &str) -> deserialize::Result<Decimal> {
+    let res = if s.contains(['e', 'E']) {
+        Decimal::from_scientific(s)
+    } else {
+        Decimal::from_str(s)
+    };
+    res.map_err(|e| {
+        format!(
+            "Numeric value `{}` cannot be represented as `Decimal`: {}",
+            s, e
+        )
+        .into()
+    })
+}
+
+impl FromSql<Numeric, Oracle> for Decimal {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                let s = <String as oracle::sql_type::FromSql>::from_sql(raw_value)?;
+                parse_decimal(&s)
+            }
+            InnerValue::Numeric(s) => parse_decimal(&s),
+            _ => Err("Got invalid value for Decimal".into()),
+        }
+    }
+}
+
+impl ToSql<Numeric, Oracle> for Decimal {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        out.set_value(BindValue::Owned(Box::new(NumberWrapper(self.to_string()))));
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,119 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::AsExpression;
+use diesel::query_builder::QueryId;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{HasSqlType, SqlType};
+
+use super::{OciDataType, OciTypeMetadata};
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::bind_collector::BindValue;
+use crate::oracle::connection::{InnerValue, OracleValue};
+
+/// The oracle `INTERVAL YEAR TO MONTH` sql type
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct IntervalYearToMonth;
+
+impl HasSqlType<IntervalYearToMonth> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::IntervalYM,
+        }
+    }
+}
+
+/// The oracle `INTERVAL DAY TO SECOND` sql type
+///
+/// With the `chrono` feature values are loaded and bound as
+/// `chrono::Duration`. Negative intervals are supported, the fractional
+/// seconds are bound with nanosecond precision and rounded by oracle to the
+/// precision of the column, e.g. to microseconds for
+/// `INTERVAL DAY TO SECOND(6)`. Durations of a billion days or more do not
+/// fit into an interval and fail to bind.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct IntervalDayToSecond;
+
+impl HasSqlType<IntervalDayToSecond> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::IntervalDS,
+        }
+    }
+}
+
+/// An interval of years and months, as stored by `INTERVAL YEAR TO MONTH`
+///
+/// Values are always normalized the same way oracle does it: the number of
+/// months is in the range of `-11..=11` and has the same sign as the number
+/// of years, so 13 months are represented as 1 year and 1 month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord, AsExpression)]
+#[diesel(sql_type = IntervalYearToMonth)]
+pub struct YearMonthInterval {
+    years: i32,
+    months: i32,
+}
+
+impl YearMonthInterval {
+    /// Construct a new interval from the given years and months
+    ///
+    /// Returns `None` if the normalized number of years does not fit into a `i32`.
+    pub fn new(years: i32, months: i32) -> Option<Self> {
+        Self::from_months(i64::from(years) * 12 + i64::from(months))
+    }
+
+    /// Construct a new interval from a total number of months
+    ///
+    /// Returns `None` if the normalized number of years does not fit into a `i32`.
+    pub fn from_months(months: i64) -> Option<Self> {
+        Some(Self {
+            years: i32::try_from(months / 12).ok()?,
+            months: (months % 12) as i32,
+        })
+    }
+
+    /// The number of years
+    pub fn years(&self) -> i32 {
+        self.years
+    }
+
+    /// The number of months in addition to the years, in the range of `-11..=11`
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// The total number of months covered by this interval
+    pub fn total_months(&self) -> i64 {
+        i64::from(self.years) * 12 + i64::from(self.months)
+    }
+}
+
+impl From<oracle::sql_type::IntervalYM> for YearMonthInterval {
+    fn from(i: oracle::sql_type::IntervalYM) -> Self {
+        Self {
+            years: i.years(),
+            months: i.months(),
+        }
+    }
+}
+
+impl FromSql<IntervalYearToMonth, Oracle> for YearMonthInterval {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                let i = <oracle::sql_type::IntervalYM as oracle::sql_type::FromSql>::from_sql(
+                    raw_value,
+                )?;
+                Ok(i.into())
+            }
+            InnerValue::IntervalYM(i) => Ok(i),
+            _ => Err("Got invalid value for an year to month interval".into()),
+        }
+    }
+}
+
+impl ToSql<IntervalYearToMonth, Oracle> for YearMonthInterval {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        let i = oracle::sql_type::IntervalYM::new(self.years, self.months)?;
+        out.set_value(BindValue::Owned(Box::new(i)));
+        Ok(IsNull::No)
+    }
+}
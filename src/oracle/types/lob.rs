@@ -0,0 +1,140 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::AsExpression;
+use diesel::internal::derives::as_expression::Bound;
+use diesel::query_builder::QueryId;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{Binary, HasSqlType, SqlType, Text};
+
+use super::{OciDataType, OciTypeMetadata};
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::bind_collector::{BindValue, BlobRef, ClobRef};
+use crate::oracle::connection::{InnerValue, OracleValue};
+
+/// The oracle `CLOB` sql type
+///
+/// Loading a `CLOB` column as [`Text`] works as well, this type is only
+/// required for values returned via a `RETURNING` clause. These are
+/// received as `CLOB` as well, so their size is not limited by
+/// [`OciConnection::set_returning_bind_size`](crate::OciConnection::set_returning_bind_size).
+/// Values are always bound as `CLOB`, independent of their size.
+///
+/// See [`OciRow::clob`](crate::OciRow::clob) to read large values as stream.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct Clob;
+
+/// The oracle `BLOB` sql type
+///
+/// This is the binary counterpart to [`Clob`].
+///
+/// See [`OciRow::blob`](crate::OciRow::blob) to read large values as stream.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct Blob;
+
+impl HasSqlType<Clob> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::Clob,
+        }
+    }
+}
+
+impl HasSqlType<Blob> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::Blob,
+        }
+    }
+}
+
+impl FromSql<Clob, Oracle> for String {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Clob(s) => Ok(s),
+            _ => <String as FromSql<Text, Oracle>>::from_sql(raw),
+        }
+    }
+}
+
+impl ToSql<Clob, Oracle> for str {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        out.set_value(BindValue::Clob(ClobRef(self)));
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Clob, Oracle> for String {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        <str as ToSql<Clob, Oracle>>::to_sql(self, out)
+    }
+}
+
+// `#[derive(AsExpression)]` cannot be used here, see `TimestampWithTimeZone`
+impl AsExpression<Clob> for String {
+    type Expression = Bound<Clob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Clob> for &'a String {
+    type Expression = Bound<Clob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Clob> for &'a str {
+    type Expression = Bound<Clob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl FromSql<Blob, Oracle> for Vec<u8> {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Blob(b) => Ok(b),
+            _ => <Vec<u8> as FromSql<Binary, Oracle>>::from_sql(raw),
+        }
+    }
+}
+
+impl ToSql<Blob, Oracle> for [u8] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        out.set_value(BindValue::Blob(BlobRef(self)));
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Blob, Oracle> for Vec<u8> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        <[u8] as ToSql<Blob, Oracle>>::to_sql(self, out)
+    }
+}
+
+impl AsExpression<Blob> for Vec<u8> {
+    type Expression = Bound<Blob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Blob> for &'a Vec<u8> {
+    type Expression = Bound<Blob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Blob> for &'a [u8] {
+    type Expression = Bound<Blob, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
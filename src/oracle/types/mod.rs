@@ -2,10 +2,20 @@
 extern crate diesel_dynamic_schema;
 
 use super::backend::*;
+use diesel::query_builder::QueryId;
 use diesel::sql_types::*;
 use std::hash::Hash;
 
+mod interval;
+mod lob;
+mod object;
 mod primitives;
+mod rowid;
+
+pub use self::interval::{IntervalDayToSecond, IntervalYearToMonth, YearMonthInterval};
+pub use self::lob::{Blob, Clob};
+pub use self::object::Object;
+pub use self::rowid::Rowid;
 
 /// Oracle specfic metadata about the type of a bind value
 #[derive(Clone, Copy)]
@@ -55,6 +65,52 @@ pub enum OciDataType {
     Time,
     /// A timestamp value
     Timestamp,
+    /// An arbitrary precision numeric value
+    Numeric,
+    /// An interval of years and months
+    IntervalYM,
+    /// An interval of days, hours, minutes and seconds
+    IntervalDS,
+    /// A timestamp with an explicit time zone offset
+    TimestampTz,
+    /// A character large object
+    Clob,
+    /// A binary large object
+    Blob,
+    /// A user defined object or collection type
+    Object,
+    /// A `ROWID` or `UROWID` value
+    Rowid,
+}
+
+/// Controls how integer values that do not fit into the requested
+/// rust type are handled while loading them from the database
+///
+/// Oracle stores integers as `NUMBER`, which can hold values far outside
+/// of the range of `i16`, `i32` or `i64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum NumericOverflowPolicy {
+    /// Return a deserialization error (the default)
+    #[default]
+    Error,
+    /// Clamp the value to the minimal/maximal value of the target type
+    Saturate,
+    /// Keep only the low order bits of the value, like an `as` cast would do
+    Truncate,
+}
+
+/// Controls which `NUMBER` values are accepted while loading them as `bool`
+///
+/// Oracle (before 23c) has no boolean column type, so booleans are stored
+/// as `NUMBER`, with `1` for `true` and `0` for `false`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum BoolTruthiness {
+    /// `0` is `false`, any other value is `true` (the default)
+    #[default]
+    NonZero,
+    /// `0` is `false`, `1` is `true`, any other value results in a
+    /// deserialization error
+    Strict,
 }
 
 impl HasSqlType<SmallInt> for Oracle {
@@ -129,6 +185,21 @@ impl HasSqlType<Timestamp> for Oracle {
     }
 }
 
+/// The oracle `TIMESTAMP WITH TIME ZONE` sql type
+///
+/// In contrast to [`Timestamp`] the time zone offset of the value
+/// is stored as well.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct TimestampWithTimeZone;
+
+impl HasSqlType<TimestampWithTimeZone> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::TimestampTz,
+        }
+    }
+}
+
 impl HasSqlType<Bool> for Oracle {
     fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
         OciTypeMetadata {
@@ -145,6 +216,14 @@ impl HasSqlType<Date> for Oracle {
     }
 }
 
+impl HasSqlType<Numeric> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::Numeric,
+        }
+    }
+}
+
 #[cfg(feature = "dynamic-schema")]
 mod dynamic_schema_impls {
 
@@ -182,5 +261,11 @@ mod dynamic_schema_impls {
     }
 }
 
+#[cfg(feature = "bigdecimal")]
+mod big_decimal;
+
 #[cfg(feature = "chrono-time")]
 mod chrono_date_time;
+
+#[cfg(feature = "rust_decimal")]
+mod decimal;
@@ -0,0 +1,86 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::AsExpression;
+use diesel::internal::derives::as_expression::Bound;
+use diesel::query_builder::QueryId;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{HasSqlType, SqlType};
+
+use super::{OciDataType, OciTypeMetadata};
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::bind_collector::BindValue;
+use crate::oracle::connection::{InnerValue, OracleValue};
+
+/// A user defined object or collection type (`CREATE TYPE …`)
+///
+/// Values are represented as [`oracle::sql_type::Object`] and constructed
+/// from the type descriptor returned by
+/// [`OciConnection::object_type`](crate::OciConnection::object_type):
+///
+/// ```no_run
+/// # use diesel::prelude::*;
+/// # use diesel_oci::{Object, OciConnection};
+/// # table! { shapes { id -> Integer, origin -> diesel_oci::Object, } }
+/// # fn run_test(conn: &mut OciConnection) -> QueryResult<()> {
+/// let point_type = conn.object_type("POINT_TYPE")?;
+/// let mut origin = point_type.new_object().unwrap();
+/// origin.set("X", &0).unwrap();
+/// origin.set("Y", &0).unwrap();
+/// diesel::insert_into(shapes::table)
+///     .values((shapes::id.eq(1), shapes::origin.eq(&origin)))
+///     .execute(conn)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Values of `Nullable<Object>` columns are given as `Option`. The object
+/// type of `None` cannot be derived from the sql type, so it is bound as
+/// untyped `NULL`.
+///
+/// Object columns can be returned via `RETURNING` as well. Their object
+/// type is looked up by describing the returned expressions, which costs
+/// an additional round trip per statement.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct Object;
+
+impl HasSqlType<Object> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::Object,
+        }
+    }
+}
+
+impl FromSql<Object, Oracle> for oracle::sql_type::Object {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        match raw.inner {
+            InnerValue::Raw { raw_value, .. } => {
+                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+            }
+            InnerValue::Object(o) => Ok(o),
+            _ => Err("Got invalid value for an object".into()),
+        }
+    }
+}
+
+impl ToSql<Object, Oracle> for oracle::sql_type::Object {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        out.set_value(BindValue::Borrowed(self));
+        Ok(IsNull::No)
+    }
+}
+
+impl AsExpression<Object> for oracle::sql_type::Object {
+    type Expression = Bound<Object, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Object> for &'a oracle::sql_type::Object {
+    type Expression = Bound<Object, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
@@ -1,17 +1,156 @@
-use crate::oracle::connection::bind_collector::BindValue;
+use crate::oracle::connection::bind_collector::{BindValue, BlobRef, ClobRef};
 use crate::oracle::connection::{InnerValue, OracleValue};
+use crate::oracle::types::{BoolTruthiness, NumericOverflowPolicy};
 use crate::oracle::Oracle;
 use diesel::deserialize::{self, FromSql};
 use diesel::serialize::{self, ToSql};
 use diesel::sql_types::*;
 
+trait OracleInteger: Sized + TryFrom<i128> + oracle::sql_type::FromSql {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn wrapping_from(v: i128) -> Self;
+}
+
+macro_rules! impl_oracle_integer {
+    ($($t: ty,)*) => {
+        $(
+            impl OracleInteger for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn wrapping_from(v: i128) -> Self {
+                    v as $t
+                }
+            }
+        )*
+    }
+}
+
+impl_oracle_integer!(i16, i32, i64,);
+
+fn integer_from_sql<T: OracleInteger>(
+    raw_value: &oracle::SqlValue,
+    policy: NumericOverflowPolicy,
+) -> deserialize::Result<T> {
+    match <T as oracle::sql_type::FromSql>::from_sql(raw_value) {
+        Ok(v) => Ok(v),
+        Err(e @ oracle::Error::OutOfRange(_)) | Err(e @ oracle::Error::ParseError(_)) => {
+            integer_from_text(&raw_value.get::<String>()?, policy).ok_or_else(|| e.into())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Convert the textual representation of an oracle number
+///
+/// Returns `None` if it is not an integer at all, or if it is out of the
+/// range of `T` and `policy` is [`NumericOverflowPolicy::Error`]
+fn integer_from_text<T: OracleInteger>(number: &str, policy: NumericOverflowPolicy) -> Option<T> {
+    let number = parse_integer(number)?;
+    // Columns with a negative scale (`NUMBER(p, -s)`) only contain
+    // integers, but are not necessarily reported as such. So accept
+    // anything that is an integer in range of the target type.
+    if let Some(v) = number.checked.and_then(|v| T::try_from(v).ok()) {
+        return Some(v);
+    }
+    match (policy, number.checked) {
+        (NumericOverflowPolicy::Error, _) => None,
+        (NumericOverflowPolicy::Truncate, _) => Some(T::wrapping_from(number.wrapped)),
+        (_, Some(v)) if v < 0 => Some(T::MIN),
+        (_, Some(_)) => Some(T::MAX),
+        (_, None) if number.negative => Some(T::MIN),
+        (_, None) => Some(T::MAX),
+    }
+}
+
+// Integers returned via a `RETURNING` clause are received as text, so that
+// the overflow policy applies to them as well
+fn integer_from_numeric<T: OracleInteger>(
+    number: &str,
+    policy: NumericOverflowPolicy,
+) -> deserialize::Result<T> {
+    integer_from_text(number, policy).ok_or_else(|| {
+        format!(
+            "Got invalid value for {}: {}",
+            std::any::type_name::<T>(),
+            number
+        )
+        .into()
+    })
+}
+
+struct ParsedInteger {
+    negative: bool,
+    /// `None` if the value does not fit into an `i128`
+    checked: Option<i128>,
+    /// The value modulo 2^128
+    wrapped: i128,
+}
+
+/// Parse the textual representation of an oracle number
+///
+/// Returns `None` if the value is not an integer
+fn parse_integer(number: &str) -> Option<ParsedInteger> {
+    let (negative, number) = match number.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+    let (mantissa, mut exponent) = match number.find(|c| c == 'e' || c == 'E') {
+        Some(pos) => (&number[..pos], number[pos + 1..].parse::<i64>().ok()?),
+        None => (number, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut digits = int_part
+        .chars()
+        .chain(frac_part.chars())
+        .collect::<Vec<_>>();
+    exponent -= frac_part.len() as i64;
+    while exponent < 0 {
+        if digits.pop()? != '0' {
+            return None;
+        }
+        exponent += 1;
+    }
+
+    let mut checked = Some(0u128);
+    let mut wrapped = 0u128;
+    for d in digits {
+        let d = d.to_digit(10)? as u128;
+        checked = checked
+            .and_then(|c| c.checked_mul(10))
+            .and_then(|c| c.checked_add(d));
+        wrapped = wrapped.wrapping_mul(10).wrapping_add(d);
+    }
+    // 10^128 is a multiple of 2^128, so the wrapped value stays 0 after that
+    for _ in 0..exponent.min(128) {
+        checked = checked.and_then(|c| c.checked_mul(10));
+        wrapped = wrapped.wrapping_mul(10);
+    }
+
+    let checked = checked
+        .and_then(|c| i128::try_from(c).ok())
+        .map(|c| if negative { -c } else { c });
+    let wrapped = if negative {
+        (wrapped as i128).wrapping_neg()
+    } else {
+        wrapped as i128
+    };
+    Some(ParsedInteger {
+        negative,
+        checked,
+        wrapped,
+    })
+}
+
 impl FromSql<SmallInt, Oracle> for i16 {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
             InnerValue::Raw { raw_value, .. } => {
-                <i16 as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+                integer_from_sql(raw_value, raw.numeric_overflow_policy)
             }
-            InnerValue::SmallInt(v) => Ok(v),
+            InnerValue::Numeric(n) => integer_from_numeric(&n, raw.numeric_overflow_policy),
             _ => Err("Got invalid value for i16".into()),
         }
     }
@@ -28,9 +167,9 @@ impl FromSql<Integer, Oracle> for i32 {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
             InnerValue::Raw { raw_value, .. } => {
-                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+                integer_from_sql(raw_value, raw.numeric_overflow_policy)
             }
-            InnerValue::Integer(i) => Ok(i),
+            InnerValue::Numeric(n) => integer_from_numeric(&n, raw.numeric_overflow_policy),
             _ => Err("Got invalid value for i32".into()),
         }
     }
@@ -47,9 +186,9 @@ impl FromSql<BigInt, Oracle> for i64 {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
             InnerValue::Raw { raw_value, .. } => {
-                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
+                integer_from_sql(raw_value, raw.numeric_overflow_policy)
             }
-            InnerValue::BigInt(i) => Ok(i),
+            InnerValue::Numeric(n) => integer_from_numeric(&n, raw.numeric_overflow_policy),
             _ => Err("Got invalid value for i64".into()),
         }
     }
@@ -62,12 +201,46 @@ impl ToSql<BigInt, Oracle> for i64 {
     }
 }
 
+trait OracleFloat: Sized + oracle::sql_type::FromSql {
+    fn from_f64(v: f64) -> Self;
+}
+
+impl OracleFloat for f32 {
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+}
+
+impl OracleFloat for f64 {
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+}
+
+// `BINARY_FLOAT` and `BINARY_DOUBLE` columns can contain `NaN` and the
+// infinities, which fail to convert if the column type does not match the
+// requested rust type. Oracle renders them as `Nan`, `Inf` and `-Inf`,
+// which rust parses as well (ignoring the case), so fall back to the
+// textual representation then.
+fn float_from_sql<T: OracleFloat>(raw_value: &oracle::SqlValue) -> deserialize::Result<T> {
+    match <T as oracle::sql_type::FromSql>::from_sql(raw_value) {
+        Ok(v) => Ok(v),
+        Err(e @ oracle::Error::OutOfRange(_))
+        | Err(e @ oracle::Error::ParseError(_))
+        | Err(e @ oracle::Error::InvalidTypeConversion(..)) => {
+            match raw_value.get::<String>()?.trim().parse::<f64>() {
+                Ok(v) => Ok(T::from_f64(v)),
+                Err(_) => Err(e.into()),
+            }
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
 impl FromSql<Float, Oracle> for f32 {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
-            InnerValue::Raw { raw_value, .. } => {
-                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
-            }
+            InnerValue::Raw { raw_value, .. } => float_from_sql(raw_value),
             InnerValue::Float(f) => Ok(f),
             _ => Err("Got invalid value for f32".into()),
         }
@@ -97,15 +270,34 @@ impl ToSql<Float, Oracle> for f32 {
 impl FromSql<Double, Oracle> for f64 {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
-            InnerValue::Raw { raw_value, .. } => {
-                <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
-            }
+            InnerValue::Raw { raw_value, .. } => float_from_sql(raw_value),
             InnerValue::Double(f) => Ok(f),
             _ => Err("Got invalid value for f64".into()),
         }
     }
 }
 
+/// Binds the textual representation of a number as oracle `NUMBER`
+///
+/// This avoids any precision loss and any dependency on the
+/// `NLS_NUMERIC_CHARACTERS` setting of the session
+#[cfg_attr(
+    not(any(feature = "rust_decimal", feature = "bigdecimal")),
+    allow(dead_code)
+)]
+pub(super) struct NumberWrapper(pub(super) String);
+
+impl oracle::sql_type::ToSql for NumberWrapper {
+    fn oratype(&self, _conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        Ok(oracle::sql_type::OracleType::Number(0, 0))
+    }
+
+    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+        val.set(&self.0)?;
+        Ok(())
+    }
+}
+
 struct BinaryDoubleWrapper(f64);
 
 impl oracle::sql_type::ToSql for BinaryDoubleWrapper {
@@ -126,6 +318,12 @@ impl ToSql<Double, Oracle> for f64 {
     }
 }
 
+/// Loading a value as `Text` works for any column type
+///
+/// Non textual values, especially dates, timestamps and intervals, are
+/// returned in their canonical oracle string representation (for example
+/// `2020-01-02 03:04:05.123456789` or `+02-03`). This is meant as generic
+/// fallback for types without a dedicated mapping.
 impl FromSql<Text, Oracle> for String {
     fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
         match raw.inner {
@@ -133,14 +331,55 @@ impl FromSql<Text, Oracle> for String {
                 <Self as oracle::sql_type::FromSql>::from_sql(raw_value).map_err(Into::into)
             }
             InnerValue::Text(s) => Ok(s),
+            InnerValue::Numeric(s) => Ok(s),
+            InnerValue::IntervalYM(i) => {
+                Ok(oracle::sql_type::IntervalYM::new(i.years(), i.months())?.to_string())
+            }
+            InnerValue::IntervalDS(i) => Ok(i.to_string()),
+            #[cfg(feature = "chrono")]
+            InnerValue::Date(d) => {
+                use chrono_time::Datelike;
+                Ok(format!(
+                    "{:04}-{:02}-{:02} 00:00:00",
+                    d.year(),
+                    d.month(),
+                    d.day()
+                ))
+            }
+            #[cfg(feature = "chrono")]
+            InnerValue::Timestamp(t) => {
+                use chrono_time::{Datelike, Timelike};
+                Ok(format!(
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:09}",
+                    t.year(),
+                    t.month(),
+                    t.day(),
+                    t.hour(),
+                    t.minute(),
+                    t.second(),
+                    t.nanosecond()
+                ))
+            }
+            #[cfg(feature = "chrono")]
+            InnerValue::TimestampTz(t) => Ok(t.format("%Y-%m-%d %H:%M:%S%.9f %:z").to_string()),
             _ => Err("Got invalid value for text".into()),
         }
     }
 }
 
+/// The maximal size in bytes of a text value that is bound as `VARCHAR2`
+///
+/// Larger values are bound as temporary `CLOB` instead, as oracle rejects
+/// longer `VARCHAR2` binds.
+const MAX_VARCHAR_BIND_SIZE: usize = 32767;
+
 impl ToSql<Text, Oracle> for str {
     fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Oracle>) -> serialize::Result {
-        out.set_value(BindValue::Owned(Box::new(self.to_owned())));
+        if self.len() > MAX_VARCHAR_BIND_SIZE {
+            out.set_value(BindValue::Clob(ClobRef(self)));
+        } else {
+            out.set_value(BindValue::Str(self));
+        }
         Ok(serialize::IsNull::No)
     }
 }
@@ -157,16 +396,40 @@ impl FromSql<Binary, Oracle> for Vec<u8> {
     }
 }
 
+/// The maximal size in bytes of a binary value that is bound as `RAW`
+///
+/// Larger values are bound as temporary `BLOB` instead, which also keeps
+/// batch inserts of large values from buffering all of them in memory.
+const MAX_RAW_BIND_SIZE: usize = 32767;
+
 impl ToSql<Binary, Oracle> for [u8] {
     fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Oracle>) -> serialize::Result {
-        out.set_value(BindValue::Owned(Box::new(self.to_owned())));
+        if self.len() > MAX_RAW_BIND_SIZE {
+            out.set_value(BindValue::Blob(BlobRef(self)));
+        } else {
+            out.set_value(BindValue::Owned(Box::new(self.to_owned())));
+        }
         Ok(serialize::IsNull::No)
     }
 }
 
 impl FromSql<Bool, Oracle> for bool {
-    fn from_sql(bytes: OracleValue<'_>) -> deserialize::Result<Self> {
-        FromSql::<SmallInt, Oracle>::from_sql(bytes).map(|v: i16| v != 0)
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        let value = match raw.inner {
+            // values outside of the range of `i64` are nonzero as well
+            InnerValue::Raw { raw_value, .. } => {
+                integer_from_sql::<i64>(raw_value, NumericOverflowPolicy::Saturate)?
+            }
+            InnerValue::Numeric(n) => integer_from_numeric(&n, NumericOverflowPolicy::Saturate)?,
+            _ => return Err("Got invalid value for bool".into()),
+        };
+        match (value, raw.bool_truthiness) {
+            (0, _) => Ok(false),
+            (1, _) | (_, BoolTruthiness::NonZero) => Ok(true),
+            (_, BoolTruthiness::Strict) => {
+                Err(format!("Got invalid value for bool: expected 0 or 1, got {}", value).into())
+            }
+        }
     }
 }
 
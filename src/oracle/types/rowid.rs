@@ -0,0 +1,76 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::expression::AsExpression;
+use diesel::internal::derives::as_expression::Bound;
+use diesel::query_builder::QueryId;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{HasSqlType, SqlType, Text};
+
+use super::{OciDataType, OciTypeMetadata};
+use crate::oracle::backend::Oracle;
+use crate::oracle::connection::OracleValue;
+
+/// The oracle `ROWID` and `UROWID` sql types
+///
+/// Values are represented by their textual (base 64) representation, like
+/// `AAAR3sAAEAAAACXAAA`, which is what oracle accepts when comparing a
+/// `ROWID` with a string. This is the type of the
+/// [`rowid()`](crate::query_builder::rowid) pseudo column, it can be used
+/// for `ROWID` columns in `table!` as well.
+///
+/// Strings compare with a `Rowid` without an explicit conversion, so a
+/// loaded rowid can be used as bind parameter again. `Nullable<Rowid>`
+/// columns need to be compared with `Some(value)`.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+pub struct Rowid;
+
+impl HasSqlType<Rowid> for Oracle {
+    fn metadata(_: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        OciTypeMetadata {
+            tpe: OciDataType::Rowid,
+        }
+    }
+}
+
+impl FromSql<Rowid, Oracle> for String {
+    fn from_sql(raw: OracleValue<'_>) -> deserialize::Result<Self> {
+        <String as FromSql<Text, Oracle>>::from_sql(raw)
+    }
+}
+
+// rowids are bound as `VARCHAR2`, oracle converts them implicitly
+impl ToSql<Rowid, Oracle> for str {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        <str as ToSql<Text, Oracle>>::to_sql(self, out)
+    }
+}
+
+impl ToSql<Rowid, Oracle> for String {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Oracle>) -> serialize::Result {
+        <str as ToSql<Rowid, Oracle>>::to_sql(self, out)
+    }
+}
+
+// `#[derive(AsExpression)]` cannot be used here, see `TimestampWithTimeZone`
+impl AsExpression<Rowid> for String {
+    type Expression = Bound<Rowid, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Rowid> for &'a String {
+    type Expression = Bound<Rowid, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Rowid> for &'a str {
+    type Expression = Bound<Rowid, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}